@@ -5,6 +5,8 @@ use pallet_evm_coder_substrate::{
 	execution::{PreDispatch, Result},
 	frontier_contract,
 };
+use pallet_xcm_transfers::transfer::{self, Beneficiary};
+use xcm::latest::WeightLimit;
 
 use super::*;
 
@@ -43,7 +45,8 @@ impl<T: Config> NativeFungibleHandle<T> {
 	fn approve(&mut self, caller: Caller, spender: Address, amount: U256) -> Result<bool> {
 		self.consume_store_writes(1)?;
 
-		let amount = amount.try_into().map_err(|_| "amount overflow")?;
+		// Amounts above `u128::MAX` are treated as an infinite approval.
+		let amount = amount.try_into().unwrap_or(u128::MAX);
 		<Pallet<T>>::approve(caller, spender, amount).map_err(dispatch_to_evm::<T>)?;
 
 		Ok(true)
@@ -111,7 +114,10 @@ impl<T: Config> NativeFungibleHandle<T> {
 
 		let value = value.try_into().map_err(|_| "value overflow")?;
 
-		<Pallet<T>>::spend_allowance(&account, &caller, value).map_err(dispatch_to_evm::<T>)?;
+		// Accounts with the BURN_ANY permission don't need an allowance.
+		if <Pallet<T>>::check_account_permissions(&caller, AccountPermissions::BURN_ANY).is_err() {
+			<Pallet<T>>::spend_allowance(&account, &caller, value).map_err(dispatch_to_evm::<T>)?;
+		}
 		<Pallet<T>>::burn(&account, value).map_err(dispatch_to_evm::<T>)
 	}
 }
@@ -131,12 +137,91 @@ impl<T: Config> NativeFungibleHandle<T> {
 	}
 }
 
-#[solidity_interface(name = XcmExtensions, is(ERC20), enum(derive(PreDispatch)), enum_attr(weight))]
+// The receiver is `bytes32`, addresses are left-padded with zeros.
+// The message id is the topic of the XCM message sent to the destination.
+// Events are emitted by the called contract, other assets sent along are reported
+// by `CrossChainAssetTransferInitiated`.
+#[derive(ToLog)]
+pub enum XcmEvents {
+	CrossChainTransferInitiated {
+		chain_id: u64,
+		receiver: H256,
+		amount: U256,
+		#[indexed]
+		message_id: H256,
+	},
+	CrossChainAssetTransferInitiated {
+		#[indexed]
+		asset: Address,
+		chain_id: u64,
+		receiver: H256,
+		amount: U256,
+		#[indexed]
+		message_id: H256,
+	},
+}
+
 impl<T: Config> NativeFungibleHandle<T>
 where
 	<T as frame_system::Config>::RuntimeOrigin: From<EthereumOrigin>,
 {
-	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets() + T::DbWeight::get().reads(2_u64))]
+	fn transfer_to(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		beneficiary: Beneficiary,
+		assets: Vec<(Address, U256)>,
+		fee_asset: Address,
+		weight_limit: WeightLimit,
+	) -> Result<()> {
+		let assets = assets
+			.into_iter()
+			.map(|(asset, amount)| Ok((asset, amount.try_into().map_err(|_| "value overflow")?)))
+			.collect::<Result<Vec<(Address, u128)>>>()?;
+
+		let message_id = transfer::transfer::<T, <T as Config>::ChainLocator>(
+			EthereumOrigin::EthereumTransaction(caller).into(),
+			caller,
+			chain_id,
+			&assets,
+			fee_asset,
+			weight_limit,
+			beneficiary,
+		)?;
+
+		let contract = T::ContractAddress::get();
+		for (asset, amount) in assets {
+			let receiver = beneficiary.to_bytes32();
+			let amount = amount.into();
+			let message_id = message_id.into();
+			let event = if asset == contract {
+				XcmEvents::CrossChainTransferInitiated {
+					chain_id,
+					receiver,
+					amount,
+					message_id,
+				}
+			} else {
+				XcmEvents::CrossChainAssetTransferInitiated {
+					asset,
+					chain_id,
+					receiver,
+					amount,
+					message_id,
+				}
+			};
+			<PalletEvm<T>>::deposit_log(event.to_log(contract));
+		}
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = XcmExtensions, is(ERC20), events(XcmEvents), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NativeFungibleHandle<T>
+where
+	<T as frame_system::Config>::RuntimeOrigin: From<EthereumOrigin>,
+{
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()) + T::DbWeight::get().reads_writes(9_u64, 7_u64))]
 	pub fn cross_chain_transfer(
 		&mut self,
 		caller: Caller,
@@ -144,57 +229,126 @@ where
 		receiver: Address,
 		amount: U256,
 	) -> Result<()> {
-		let amount = amount.try_into().map_err(|_| "value overflow")?;
-		let locator = <T as Config>::ChainLocator::get();
-		let destination = *locator.get(&chain_id).ok_or("chain not found")?;
-		let relay_network = T::UniversalLocation::get()
-			.global_consensus()
-			.map_err(|_| "unable to get global consensus")?;
-		if amount > <Pallet<T>>::balance(&caller).into() {
-			return Err(dispatch_to_evm::<T>(
-				<Error<T>>::ERC20InsufficientBalance.into(),
-			));
-		}
+		let asset = T::ContractAddress::get();
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountKey20(receiver),
+			Vec::from([(asset, amount)]),
+			asset,
+			WeightLimit::Unlimited,
+		)
+	}
 
-		// Determining the asset location relative to the relay.
-		// For relay - 0, for parachains - 1.
-		// Correctness is ensured by the correct configuration of the `ChainLocator`.
-		let parents = match (destination.parent_count(), destination.interior()) {
-			(1, Junctions::Here) | (1, Junctions::X1(Junction::Parachain(_))) => Ok(1),
-			(0, Junctions::X1(Junction::Parachain(_))) => Ok(0),
-			_ => Err("unsupported location pattern"),
-		}?;
-
-		let asset = XcmAsset {
-			id: Location::new(
-				parents,
-				Junction::AccountKey20 {
-					network: Some(relay_network),
-					key: T::ContractAddress::get().into(),
-				},
-			)
-			.into(),
-			fun: Fungibility::Fungible(amount),
-		};
-
-		let beneficiary = Location::new(
-			0,
-			Junction::AccountKey20 {
-				network: Some(relay_network),
-				key: receiver.into(),
-			},
-		);
-
-		let fee_asset_item = 0;
-		<PalletXcm<T>>::limited_teleport_assets(
-			EthereumOrigin::EthereumTransaction(caller).into(),
-			Box::new(destination.into()),
-			Box::new(beneficiary.into()),
-			Box::new(asset.into()),
-			fee_asset_item,
+	/// Transfer to a Substrate account, e.g. sr25519, on the destination chain.
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()) + T::DbWeight::get().reads_writes(9_u64, 7_u64))]
+	pub fn cross_chain_transfer_to_substrate(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		receiver: H256,
+		amount: U256,
+	) -> Result<()> {
+		let asset = T::ContractAddress::get();
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountId32(receiver),
+			Vec::from([(asset, amount)]),
+			asset,
 			WeightLimit::Unlimited,
 		)
-		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Transfer several assets, identified by the addresses of their contracts,
+	/// paying for the execution on the destination chain with the fee asset.
+	///
+	/// The weight limit is the maximum execution time bought on the destination,
+	/// `uint64` max means no limit.
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()).saturating_mul(assets.len().max(1) as u64) + T::DbWeight::get().reads_writes(4 + 5 * assets.len() as u64, 5 + 2 * assets.len() as u64))]
+	#[solidity(rename_selector = "crossChainTransfer")]
+	pub fn cross_chain_transfer_assets(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		receiver: Address,
+		assets: Vec<(Address, U256)>,
+		fee_asset: Address,
+		weight_limit: u64,
+	) -> Result<()> {
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountKey20(receiver),
+			assets,
+			fee_asset,
+			transfer::weight_limit(weight_limit),
+		)
+	}
+
+	/// Transfer several assets to a Substrate account, e.g. sr25519, on the destination chain.
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()).saturating_mul(assets.len().max(1) as u64) + T::DbWeight::get().reads_writes(4 + 5 * assets.len() as u64, 5 + 2 * assets.len() as u64))]
+	#[solidity(rename_selector = "crossChainTransferToSubstrate")]
+	pub fn cross_chain_transfer_assets_to_substrate(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		receiver: H256,
+		assets: Vec<(Address, U256)>,
+		fee_asset: Address,
+		weight_limit: u64,
+	) -> Result<()> {
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountId32(receiver),
+			assets,
+			fee_asset,
+			transfer::weight_limit(weight_limit),
+		)
+	}
+
+	/// Returns the status of the cross-chain transfer by the id of its message:
+	/// 0 - unknown, 1 - pending, 2 - delivered, 3 - failed, 4 - trapped.
+	fn cross_chain_transfer_status(&self, message_id: H256) -> Result<u8> {
+		self.consume_store_reads(1)?;
+		Ok(transfer::status_code::<T>(message_id))
+	}
+
+	/// Returns true if cross-chain transfers are paused.
+	fn bridging_paused(&self) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<pallet_bridge_flows::Pallet<T>>::is_bridging_paused(
+			&T::ContractAddress::get(),
+		))
+	}
+
+	/// Forbid cross-chain transfers.
+	///
+	/// Only available to the sudo key or an account with the BRIDGE_OPERATOR permission.
+	fn pause_bridging(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(&caller, AccountPermissions::BRIDGE_OPERATOR)
+			.map_err(dispatch_to_evm::<T>)?;
+
+		<pallet_bridge_flows::Pallet<T>>::set_bridging_paused(T::ContractAddress::get(), true);
+		Ok(())
+	}
+
+	/// Allow cross-chain transfers again.
+	///
+	/// Only available to the sudo key or an account with the BRIDGE_OPERATOR permission.
+	fn unpause_bridging(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(&caller, AccountPermissions::BRIDGE_OPERATOR)
+			.map_err(dispatch_to_evm::<T>)?;
+
+		<pallet_bridge_flows::Pallet<T>>::set_bridging_paused(T::ContractAddress::get(), false);
+		Ok(())
 	}
 }
 
@@ -205,7 +359,10 @@ impl<T: Config> NativeFungibleHandle<T> {
 	/// Permissions bits.
 	///
 	/// 1 bit: allow account to mint new tokens.
-	/// 2 - 8 bits: reserved.
+	/// 4 bit: allow account to burn tokens of any holder without allowance,
+	/// must be granted explicitly, even to the sudo key.
+	/// 6 bit: allow account to pause and unpause cross-chain transfers.
+	/// 2, 3, 5, 7 - 64 bits: reserved.
 	#[allow(unused_variables)]
 	fn set_account_permissions(
 		&mut self,
@@ -223,6 +380,84 @@ impl<T: Config> NativeFungibleHandle<T> {
 
 		Ok(())
 	}
+
+	/// Returns permissions bits of the account.
+	///
+	/// See `setAccountPermissions` for their meaning.
+	fn permissions_of(&self, account: Address) -> Result<u64> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::permissions_of(&account).bits())
+	}
+}
+
+#[solidity_interface(name = AllowanceExtensions, is(ERC20), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NativeFungibleHandle<T> {
+	/// Atomically increases the allowance granted to `spender` by the caller.
+	fn increase_allowance(
+		&mut self,
+		caller: Caller,
+		spender: Address,
+		added_value: U256,
+	) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		let added_value = added_value.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::increase_allowance(caller, spender, added_value)
+			.map_err(dispatch_to_evm::<T>)?;
+		Ok(true)
+	}
+
+	/// Atomically decreases the allowance granted to `spender` by the caller.
+	fn decrease_allowance(
+		&mut self,
+		caller: Caller,
+		spender: Address,
+		subtracted_value: U256,
+	) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		let subtracted_value = subtracted_value.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::decrease_allowance(caller, spender, subtracted_value)
+			.map_err(dispatch_to_evm::<T>)?;
+		Ok(true)
+	}
+}
+
+/// Signed approvals, see [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612).
+#[solidity_interface(name = ERC20Permit, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> NativeFungibleHandle<T> {
+	/// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+	/// given `owner`'s signed approval.
+	#[allow(clippy::too_many_arguments)]
+	fn permit(
+		&mut self,
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::permit(owner, spender, value, deadline, v, r, s).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Returns the current nonce for `owner`, which must be included in the signed approval.
+	fn nonces(&self, owner: Address) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::nonce(&owner))
+	}
+
+	/// Returns the domain separator used in the encoding of the signed approval.
+	#[solidity(rename_selector = "DOMAIN_SEPARATOR")]
+	fn domain_separator(&self) -> Result<H256> {
+		Ok(<Pallet<T>>::domain_separator())
+	}
 }
 
 /// Implements [`OnMethodCall`], which delegates call to [`NativeFungibleHandle`]
@@ -257,7 +492,15 @@ where
 
 #[solidity_interface(
 	name = NativeFungible,
-	is(ERC20, ERC20Burnable, ERC20Mintable, XcmExtensions, PermissionsExtensions),
+	is(
+		ERC20,
+		ERC20Burnable,
+		ERC20Mintable,
+		XcmExtensions,
+		PermissionsExtensions,
+		ERC20Permit,
+		AllowanceExtensions,
+	),
 	enum(derive(PreDispatch))
 )]
 impl<T: Config> NativeFungibleHandle<T>
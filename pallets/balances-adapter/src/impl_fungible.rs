@@ -79,6 +79,10 @@ impl<T: Config> Mutate<H160> for Pallet<T> {
 			}
 			.to_log(T::ContractAddress::get()),
 		);
+		<Pallet<T>>::deposit_event(Event::Minted {
+			to: *who,
+			amount: amount.into(),
+		});
 		T::Balances::done_mint_into(cross.as_sub(), amount)
 	}
 
@@ -92,6 +96,10 @@ impl<T: Config> Mutate<H160> for Pallet<T> {
 			}
 			.to_log(T::ContractAddress::get()),
 		);
+		<Pallet<T>>::deposit_event(Event::Burned {
+			from: *who,
+			amount: amount.into(),
+		});
 		T::Balances::done_burn_from(cross.as_sub(), amount)
 	}
 
@@ -105,6 +113,10 @@ impl<T: Config> Mutate<H160> for Pallet<T> {
 			}
 			.to_log(T::ContractAddress::get()),
 		);
+		<Pallet<T>>::deposit_event(Event::Burned {
+			from: *who,
+			amount: amount.into(),
+		});
 		T::Balances::done_shelve(cross.as_sub(), amount)
 	}
 
@@ -118,6 +130,10 @@ impl<T: Config> Mutate<H160> for Pallet<T> {
 			}
 			.to_log(T::ContractAddress::get()),
 		);
+		<Pallet<T>>::deposit_event(Event::Minted {
+			to: *who,
+			amount: amount.into(),
+		});
 		T::Balances::done_restore(cross.as_sub(), amount)
 	}
 
@@ -132,6 +148,11 @@ impl<T: Config> Mutate<H160> for Pallet<T> {
 			}
 			.to_log(T::ContractAddress::get()),
 		);
+		<Pallet<T>>::deposit_event(Event::Transferred {
+			from: *source,
+			to: *dest,
+			amount: amount.into(),
+		});
 		T::Balances::done_transfer(cross_source.as_sub(), cross_dest.as_sub(), amount)
 	}
 }
@@ -14,24 +14,27 @@ use frame_support::{
 };
 pub use pallet::*;
 use pallet_balances::WeightInfo;
+use pallet_chain_registry::Destination;
 use pallet_ethereum::Origin as EthereumOrigin;
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::{SubstrateRecorder, WithRecorder};
-use pallet_xcm::{Pallet as PalletXcm, WeightInfo as PalletXcmWeightInfo};
-use sp_core::{H160, U256};
-use sp_runtime::TokenError;
-use sp_std::{boxed::Box, collections::btree_map::BTreeMap};
-use xcm::{
-	latest::{Fungibility, Junction, Junctions, MultiAsset as XcmAsset, MultiLocation as Location},
-	prelude::WeightLimit,
-};
+use pallet_xcm::WeightInfo as PalletXcmWeightInfo;
+use pallet_xcm_transfers::transfer::TransferableAssets;
+use sp_core::{H160, H256, U256};
+use sp_runtime::{traits::TryConvert, ArithmeticError, TokenError};
 pub mod eth;
 pub mod handle;
 use handle::*;
 mod impl_fungible;
+pub mod permit;
 mod types;
 use types::*;
 
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 pub(crate) type SelfWeightOf<T> = <T as Config>::WeightInfo;
 pub(crate) type ChainId = u64;
 
@@ -63,6 +66,34 @@ pub mod pallet {
 		OwnableUnauthorizedAccount,
 		UnauthorizedAccount,
 		AssetNotFound,
+		ERC2612ExpiredSignature,
+		ERC2612InvalidSigner,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Tokens were minted.
+		Minted { to: Address, amount: u128 },
+		/// Tokens were burned.
+		Burned { from: Address, amount: u128 },
+		/// Tokens were transferred.
+		Transferred {
+			from: Address,
+			to: Address,
+			amount: u128,
+		},
+		/// Allowance of `spender` over the `owner`'s tokens was changed.
+		ApprovalChanged {
+			owner: Address,
+			spender: Address,
+			amount: u128,
+		},
+		/// Permissions of an account were changed.
+		PermissionsChanged {
+			account: Address,
+			permissions: AccountPermissions,
+		},
 	}
 
 	#[pallet::storage]
@@ -79,12 +110,20 @@ pub mod pallet {
 	pub(super) type Permissions<T: Config> =
 		StorageMap<_, Blake2_128Concat, H160, AccountPermissions, ValueQuery>;
 
+	#[pallet::storage]
+	/// Nonces of the signed approvals, see [`Pallet::permit`].
+	pub(super) type Nonces<T: Config> = StorageMap<_, Blake2_128Concat, Address, U256, ValueQuery>;
+
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config<AccountId: for<'a> TryFrom<&'a [u8]>>
 		+ pallet_evm_coder_substrate::Config
 		+ pallet_xcm::Config
+		+ pallet_xcm_transfers::Config
 	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		type Balances: Mutate<Self::AccountId, Balance = Self::NativeBalance>;
 
 		type NativeBalance: Balance + Into<U256> + TryFrom<U256> + From<u128> + Into<u128>;
@@ -102,11 +141,11 @@ pub mod pallet {
 		/// Collection symbol
 		type Symbol: Get<String>;
 
-		/// The type must contain only correct
-		/// and supported 'Location', since it is used "as is"
+		/// Resolves the destination of `crossChainTransfer` and the way the asset is moved there
+		/// by the chain id and the address of the transferred asset, failing if the transfer is not allowed.
+		/// The type must return only correct and supported 'Location', since it is used "as is"
 		/// and its use does not imply deep checks
-		#[pallet::constant]
-		type ChainLocator: Get<BTreeMap<ChainId, Location>>;
+		type ChainLocator: TryConvert<(ChainId, H160), Destination>;
 
 		/// Weight information
 		type WeightInfo: WeightInfo;
@@ -136,6 +175,11 @@ pub mod pallet {
 			ensure!(spender != Address::zero(), <Error<T>>::ERC20InvalidReceiver);
 
 			<Allowance<T>>::set((&owner, &spender), amount);
+			Self::deposit_event(Event::ApprovalChanged {
+				owner,
+				spender,
+				amount,
+			});
 
 			<PalletEvm<T>>::deposit_log(
 				eth::ERC20Events::Approval {
@@ -149,17 +193,50 @@ pub mod pallet {
 			Ok(())
 		}
 
+		pub fn increase_allowance(
+			owner: Address,
+			spender: Address,
+			added_value: u128,
+		) -> DispatchResult {
+			let allowance = <Allowance<T>>::get((&owner, &spender))
+				.checked_add(added_value)
+				.ok_or(ArithmeticError::Overflow)?;
+			Self::approve(owner, spender, allowance)
+		}
+
+		pub fn decrease_allowance(
+			owner: Address,
+			spender: Address,
+			subtracted_value: u128,
+		) -> DispatchResult {
+			let allowance = <Allowance<T>>::get((&owner, &spender))
+				.checked_sub(subtracted_value)
+				.ok_or(<Error<T>>::ERC20InsufficientAllowance)?;
+			Self::approve(owner, spender, allowance)
+		}
+
 		/// Updates `owner` s allowance for `spender` based on spent `value`.
+		///
+		/// `u128::MAX` allowance is infinite and is never decreased.
 		pub fn spend_allowance(owner: &Address, spender: &Address, amount: u128) -> DispatchResult {
 			let key = (owner, spender);
 			let current_allowance = <Allowance<T>>::get(&key);
+			if current_allowance == u128::MAX {
+				return Ok(());
+			}
 
 			ensure!(
 				current_allowance >= amount,
 				<Error<T>>::ERC20InsufficientAllowance
 			);
 
-			<Allowance<T>>::mutate(&key, |allowance| *allowance -= amount);
+			let remaining = current_allowance - amount;
+			<Allowance<T>>::set(&key, remaining);
+			Self::deposit_event(Event::ApprovalChanged {
+				owner: *owner,
+				spender: *spender,
+				amount: remaining,
+			});
 
 			Ok(())
 		}
@@ -224,13 +301,24 @@ pub mod pallet {
 			} else {
 				<Permissions<T>>::insert(account, permissions);
 			}
+
+			Self::deposit_event(Event::PermissionsChanged {
+				account: *account,
+				permissions,
+			});
+		}
+
+		pub fn permissions_of(account: &Address) -> AccountPermissions {
+			<Permissions<T>>::get(account)
 		}
 
 		pub fn check_account_permissions(
 			account: &Address,
 			permissions: AccountPermissions,
 		) -> DispatchResult {
-			if Self::check_root(account).is_ok() {
+			if !permissions.intersects(AccountPermissions::EXPLICIT)
+				&& Self::check_root(account).is_ok()
+			{
 				return Ok(());
 			}
 
@@ -271,3 +359,18 @@ pub mod pallet {
 		}
 	}
 }
+
+impl<T: Config> TransferableAssets for Pallet<T> {
+	fn ensure_transferable(asset: &H160, sender: &H160, amount: u128) -> Option<DispatchResult> {
+		if *asset != T::ContractAddress::get() {
+			return None;
+		}
+		Some((|| {
+			ensure!(
+				amount <= Self::balance(sender).into(),
+				<Error<T>>::ERC20InsufficientBalance
+			);
+			Ok(())
+		})())
+	}
+}
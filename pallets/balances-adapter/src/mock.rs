@@ -0,0 +1,268 @@
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, ConstU8, Currency, Everything, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_evm::{
+	account::{BasicCrossAccountId, CrossAccountId as _},
+	EnsureAddressNever, HashedAddressMapping, SubstrateBlockHashMapping,
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use xcm::latest::prelude::*;
+use xcm_builder::{
+	AccountId32Aliases, AllowUnpaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds,
+	SignedToAccountId32,
+};
+use xcm_executor::XcmExecutor;
+
+use crate::{self as pallet_balances_adapter, *};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type CrossAccountId = BasicCrossAccountId<Test>;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		EVM: pallet_evm,
+		EvmCoderSubstrate: pallet_evm_coder_substrate,
+		XcmPallet: pallet_xcm,
+		ChainRegistry: pallet_chain_registry,
+		BridgeFlows: pallet_bridge_flows,
+		XcmTransfers: pallet_xcm_transfers,
+		BalancesAdapter: pallet_balances_adapter,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u32::MAX);
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type CrossAccountId = CrossAccountId;
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BackwardsAddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BlockGasLimit = BlockGasLimit;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressNever<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type OnMethodCall = ();
+	type OnCreate = ();
+	type ChainId = ConstU64<8888>;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type FindAuthor = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type GasLimitPovSizeRatio = ConstU64<4>;
+	type OnCheckEvmTransaction = ();
+}
+
+impl pallet_evm_coder_substrate::Config for Test {}
+
+parameter_types! {
+	pub const ThisNetwork: NetworkId = NetworkId::Polkadot;
+	pub UniversalLocation: InteriorMultiLocation = X1(GlobalConsensus(ThisNetwork::get()));
+	pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+	pub const MaxInstructions: u32 = 100;
+}
+
+type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, ThisNetwork>;
+
+pub struct XcmConfig;
+
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = ();
+	type AssetTransactor = ();
+	type OriginConverter = pallet_xcm::XcmPassthrough<RuntimeOrigin>;
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Trader = ();
+	type ResponseHandler = XcmPallet;
+	type AssetTrap = XcmPallet;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = XcmPallet;
+	type SubscriptionService = XcmPallet;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = ConstU32<64>;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+}
+
+impl pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = ();
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Nothing;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Nothing;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type TrustedLockers = ();
+	type SovereignAccountOf = AccountId32Aliases<ThisNetwork, AccountId>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+}
+
+impl pallet_chain_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
+
+impl pallet_bridge_flows::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type ChainIds = ChainRegistry;
+}
+
+impl pallet_xcm_transfers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type TransferableAssets = BalancesAdapter;
+	type TransferResponseTimeout = ConstU64<10>;
+}
+
+pub const CONTRACT: H160 = H160([0xBA; 20]);
+pub const ALICE: H160 = H160([0x11; 20]);
+pub const BOB: H160 = H160([0x22; 20]);
+pub const SUDO: AccountId = AccountId32::new([0x33; 32]);
+pub const INITIAL_BALANCE: Balance = 1_000;
+
+parameter_types! {
+	pub const ContractAddress: H160 = CONTRACT;
+	pub Name: String = String::from("Native");
+	pub Symbol: String = String::from("NTV");
+}
+
+impl pallet_balances_adapter::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balances = Balances;
+	type NativeBalance = Balance;
+	type ContractAddress = ContractAddress;
+	type Decimals = ConstU8<18>;
+	type Name = Name;
+	type Symbol = Symbol;
+	type ChainLocator = ChainRegistry;
+	type WeightInfo = ();
+}
+
+/// Balance of the `account`'s substrate mirror.
+pub fn balance(account: H160) -> u128 {
+	BalancesAdapter::balance_of(&CrossAccountId::from_eth(account))
+}
+
+/// Allowance of `spender` over the `owner`'s tokens.
+pub fn allowance(owner: H160, spender: H160) -> U256 {
+	BalancesAdapter::allowance(
+		&CrossAccountId::from_eth(owner),
+		&CrossAccountId::from_eth(spender),
+	)
+}
+
+/// Ethereum address of [`SUDO`].
+pub fn sudo() -> H160 {
+	*CrossAccountId::from_sub(SUDO).as_eth()
+}
+
+/// Makes [`SUDO`] the sudo key, as the sudo pallet stores it.
+pub fn set_sudo_key() {
+	const SUDO_STORAGE_KEY: [u8; 32] =
+		hex_literal::hex!("5c0d1176a568c1f92944340dbfed9e9c530ebca703c85910e7164cb7d1c9e47b");
+	sp_io::storage::set(&SUDO_STORAGE_KEY, SUDO.as_ref());
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(CrossAccountId::from_eth(ALICE).as_sub(), INITIAL_BALANCE);
+	});
+	ext
+}
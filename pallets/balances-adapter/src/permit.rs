@@ -0,0 +1,54 @@
+//! [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612) signed approvals.
+
+use frame_support::{dispatch::DispatchResult, traits::Get};
+use pallet_evm_coder_substrate::permit::{self, Permit, PermitError};
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+	pub fn nonce(owner: &Address) -> U256 {
+		<Nonces<T>>::get(owner)
+	}
+
+	/// Returns the EIP-712 domain separator of the contract.
+	///
+	/// The name in the domain is `Config::Name`, changing it in a runtime upgrade
+	/// invalidates the approvals already signed.
+	pub fn domain_separator() -> H256 {
+		permit::domain_separator::<T>(T::Name::get().as_bytes(), &T::ContractAddress::get())
+	}
+
+	/// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+	/// given `owner`'s signed approval.
+	///
+	/// The approval is verified against the signed `value`, which is only clamped
+	/// to the maximum balance when it is set as the allowance.
+	pub fn permit(
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> DispatchResult {
+		let nonce = Self::nonce(&owner);
+		let permit = Permit {
+			owner,
+			spender,
+			value,
+			nonce,
+			deadline,
+		};
+		permit
+			.verify::<T>(&Self::domain_separator(), v, r, s)
+			.map_err(|error| match error {
+				PermitError::Expired => <Error<T>>::ERC2612ExpiredSignature,
+				PermitError::InvalidSigner => <Error<T>>::ERC2612InvalidSigner,
+			})?;
+
+		<Nonces<T>>::insert(owner, nonce.saturating_add(U256::one()));
+		let value = value.try_into().unwrap_or(u128::MAX);
+		Self::approve(owner, spender, value)
+	}
+}
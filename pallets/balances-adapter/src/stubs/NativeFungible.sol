@@ -112,8 +112,131 @@ contract ERC20 is Dummy, ERC165, ERC20Events {
 	}
 }
 
-/// @dev the ERC-165 identifier for this interface is 0xee18d38e
-contract XcmExtensions is Dummy, ERC165, ERC20 {
+/// @dev the ERC-165 identifier for this interface is 0x9d075186
+contract AllowanceExtensions is Dummy, ERC165, ERC20 {
+	/// Atomically increases the allowance granted to `spender` by the caller.
+	/// @dev EVM selector for this function is: 0x39509351,
+	///  or in textual repr: increaseAllowance(address,uint256)
+	function increaseAllowance(address spender, uint256 addedValue) public returns (bool) {
+		require(false, stub_error);
+		spender;
+		addedValue;
+		dummy = 0;
+		return false;
+	}
+
+	/// Atomically decreases the allowance granted to `spender` by the caller.
+	/// @dev EVM selector for this function is: 0xa457c2d7,
+	///  or in textual repr: decreaseAllowance(address,uint256)
+	function decreaseAllowance(address spender, uint256 subtractedValue) public returns (bool) {
+		require(false, stub_error);
+		spender;
+		subtractedValue;
+		dummy = 0;
+		return false;
+	}
+}
+
+/// Signed approvals, see [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612).
+/// @dev the ERC-165 identifier for this interface is 0x9d8ff7da
+contract ERC20Permit is Dummy, ERC165 {
+	/// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+	/// given `owner`'s signed approval.
+	/// @dev EVM selector for this function is: 0xd505accf,
+	///  or in textual repr: permit(address,address,uint256,uint256,uint8,bytes32,bytes32)
+	function permit(
+		address owner,
+		address spender,
+		uint256 value,
+		uint256 deadline,
+		uint8 v,
+		bytes32 r,
+		bytes32 s
+	) public {
+		require(false, stub_error);
+		owner;
+		spender;
+		value;
+		deadline;
+		v;
+		r;
+		s;
+		dummy = 0;
+	}
+
+	/// Returns the current nonce for `owner`, which must be included in the signed approval.
+	/// @dev EVM selector for this function is: 0x7ecebe00,
+	///  or in textual repr: nonces(address)
+	function nonces(address owner) public view returns (uint256) {
+		require(false, stub_error);
+		owner;
+		dummy;
+		return 0;
+	}
+
+	/// Returns the domain separator used in the encoding of the signed approval.
+	/// @dev EVM selector for this function is: 0x3644e515,
+	///  or in textual repr: DOMAIN_SEPARATOR()
+	function DOMAIN_SEPARATOR() public view returns (bytes32) {
+		require(false, stub_error);
+		dummy;
+		return bytes32(0);
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0xd3a4d5ae
+contract PermissionsExtensions is Dummy, ERC165, ERC20 {
+	/// Change account permissions.
+	///
+	/// Permissions bits.
+	///
+	/// 1 bit: allow account to mint new tokens.
+	/// 4 bit: allow account to burn tokens of any holder without allowance,
+	/// must be granted explicitly, even to the sudo key.
+	/// 6 bit: allow account to pause and unpause cross-chain transfers.
+	/// 2, 3, 5, 7 - 64 bits: reserved.
+	/// @dev EVM selector for this function is: 0xd901570d,
+	///  or in textual repr: setAccountPermissions(address,uint64)
+	function setAccountPermissions(address account, uint64 permissions) public {
+		require(false, stub_error);
+		account;
+		permissions;
+		dummy = 0;
+	}
+
+	/// Returns permissions bits of the account.
+	///
+	/// See `setAccountPermissions` for their meaning.
+	/// @dev EVM selector for this function is: 0x0aa582a3,
+	///  or in textual repr: permissionsOf(address)
+	function permissionsOf(address account) public view returns (uint64) {
+		require(false, stub_error);
+		account;
+		dummy;
+		return 0;
+	}
+}
+
+/// @dev anonymous struct
+struct Tuple0 {
+	address field_0;
+	uint256 field_1;
+}
+
+/// @dev inlined interface
+contract XcmEvents {
+	event CrossChainTransferInitiated(uint64 chainId, bytes32 receiver, uint256 amount, bytes32 indexed messageId);
+	event CrossChainAssetTransferInitiated(
+		address indexed asset,
+		uint64 chainId,
+		bytes32 receiver,
+		uint256 amount,
+		bytes32 indexed messageId
+	);
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x69666339
+contract XcmExtensions is Dummy, ERC165, ERC20, XcmEvents {
 	/// @dev EVM selector for this function is: 0xee18d38e,
 	///  or in textual repr: crossChainTransfer(uint64,address,uint256)
 	function crossChainTransfer(
@@ -127,6 +250,145 @@ contract XcmExtensions is Dummy, ERC165, ERC20 {
 		amount;
 		dummy = 0;
 	}
+
+	/// Transfer to a Substrate account, e.g. sr25519, on the destination chain.
+	/// @dev EVM selector for this function is: 0x6d3002d2,
+	///  or in textual repr: crossChainTransferToSubstrate(uint64,bytes32,uint256)
+	function crossChainTransferToSubstrate(
+		uint64 chainId,
+		bytes32 receiver,
+		uint256 amount
+	) public {
+		require(false, stub_error);
+		chainId;
+		receiver;
+		amount;
+		dummy = 0;
+	}
+
+	/// Transfer several assets, identified by the addresses of their contracts,
+	/// paying for the execution on the destination chain with the fee asset.
+	///
+	/// The weight limit is the maximum execution time bought on the destination,
+	/// `uint64` max means no limit.
+	/// @dev EVM selector for this function is: 0xf3f85c1c,
+	///  or in textual repr: crossChainTransfer(uint64,address,(address,uint256)[],address,uint64)
+	function crossChainTransfer(
+		uint64 chainId,
+		address receiver,
+		Tuple0[] memory assets,
+		address feeAsset,
+		uint64 weightLimit
+	) public {
+		require(false, stub_error);
+		chainId;
+		receiver;
+		assets;
+		feeAsset;
+		weightLimit;
+		dummy = 0;
+	}
+
+	/// Transfer several assets to a Substrate account, e.g. sr25519, on the destination chain.
+	/// @dev EVM selector for this function is: 0x9de8bc75,
+	///  or in textual repr: crossChainTransferToSubstrate(uint64,bytes32,(address,uint256)[],address,uint64)
+	function crossChainTransferToSubstrate(
+		uint64 chainId,
+		bytes32 receiver,
+		Tuple0[] memory assets,
+		address feeAsset,
+		uint64 weightLimit
+	) public {
+		require(false, stub_error);
+		chainId;
+		receiver;
+		assets;
+		feeAsset;
+		weightLimit;
+		dummy = 0;
+	}
+
+	/// Returns the status of the cross-chain transfer by the id of its message:
+	/// 0 - unknown, 1 - pending, 2 - delivered, 3 - failed, 4 - trapped.
+	/// @dev EVM selector for this function is: 0x32ed2a46,
+	///  or in textual repr: crossChainTransferStatus(bytes32)
+	function crossChainTransferStatus(bytes32 messageId) public view returns (uint8) {
+		require(false, stub_error);
+		messageId;
+		dummy;
+		return 0;
+	}
+
+	/// Returns true if cross-chain transfers are paused.
+	/// @dev EVM selector for this function is: 0x52b61b52,
+	///  or in textual repr: bridgingPaused()
+	function bridgingPaused() public view returns (bool) {
+		require(false, stub_error);
+		dummy;
+		return false;
+	}
+
+	/// Forbid cross-chain transfers.
+	///
+	/// Only available to the sudo key or an account with the BRIDGE_OPERATOR permission.
+	/// @dev EVM selector for this function is: 0xae0e890a,
+	///  or in textual repr: pauseBridging()
+	function pauseBridging() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+
+	/// Allow cross-chain transfers again.
+	///
+	/// Only available to the sudo key or an account with the BRIDGE_OPERATOR permission.
+	/// @dev EVM selector for this function is: 0x4a0bea12,
+	///  or in textual repr: unpauseBridging()
+	function unpauseBridging() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x40c10f19
+contract ERC20Mintable is Dummy, ERC165, ERC20 {
+	/// @dev EVM selector for this function is: 0x40c10f19,
+	///  or in textual repr: mint(address,uint256)
+	function mint(address to, uint256 amount) public {
+		require(false, stub_error);
+		to;
+		amount;
+		dummy = 0;
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x3b5a0bf8
+contract ERC20Burnable is Dummy, ERC165, ERC20 {
+	/// @dev EVM selector for this function is: 0x42966c68,
+	///  or in textual repr: burn(uint256)
+	function burn(uint256 value) public {
+		require(false, stub_error);
+		value;
+		dummy = 0;
+	}
+
+	/// @dev EVM selector for this function is: 0x79cc6790,
+	///  or in textual repr: burnFrom(address,uint256)
+	function burnFrom(address account, uint256 value) public {
+		require(false, stub_error);
+		account;
+		value;
+		dummy = 0;
+	}
 }
 
-contract NativeFungible is Dummy, ERC165, ERC20, XcmExtensions {}
+contract NativeFungible is
+	Dummy,
+	ERC165,
+	ERC20,
+	ERC20Burnable,
+	ERC20Mintable,
+	XcmExtensions,
+	PermissionsExtensions,
+	ERC20Permit,
+	AllowanceExtensions
+{}
@@ -0,0 +1,224 @@
+use frame_support::{assert_noop, assert_ok};
+use pallet_evm_coder_substrate::permit::Permit;
+use pallet_xcm_transfers::transfer::TransferableAssets;
+use sp_core::{ecdsa, Pair};
+use sp_io::hashing::keccak_256;
+
+use crate::{mock::*, *};
+
+fn signature(pair: &ecdsa::Pair, digest: &[u8; 32]) -> [u8; 65] {
+	let signature = pair.sign_prehashed(digest);
+	let mut bytes = [0; 65];
+	bytes.copy_from_slice(signature.as_ref());
+	bytes
+}
+
+fn eth_address(pair: &ecdsa::Pair) -> H160 {
+	let digest = [0; 32];
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature(pair, &digest), &digest)
+		.expect("signature is valid");
+	H160::from_slice(&keccak_256(&public)[12..])
+}
+
+fn permit(pair: &ecdsa::Pair, spender: H160, value: U256, deadline: U256) -> DispatchResult {
+	let owner = eth_address(pair);
+	let digest = Permit {
+		owner,
+		spender,
+		value,
+		nonce: BalancesAdapter::nonce(&owner),
+		deadline,
+	}
+	.digest(&BalancesAdapter::domain_separator());
+	let signature = signature(pair, &digest);
+
+	BalancesAdapter::permit(
+		owner,
+		spender,
+		value,
+		deadline,
+		signature[64] + 27,
+		H256::from_slice(&signature[..32]),
+		H256::from_slice(&signature[32..64]),
+	)
+}
+
+#[test]
+fn transfer_moves_balance_of_substrate_mirrors() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BalancesAdapter::transfer(&ALICE, &BOB, 100));
+		System::assert_has_event(
+			Event::Transferred {
+				from: ALICE,
+				to: BOB,
+				amount: 100,
+			}
+			.into(),
+		);
+		assert_eq!(balance(ALICE), INITIAL_BALANCE - 100);
+		assert_eq!(balance(BOB), 100);
+
+		assert_noop!(
+			BalancesAdapter::transfer(&ALICE, &BOB, INITIAL_BALANCE),
+			<Error<Test>>::ERC20InsufficientBalance
+		);
+		assert_noop!(
+			BalancesAdapter::transfer(&ALICE, &H160::zero(), 1),
+			<Error<Test>>::ERC20InvalidReceiver
+		);
+	});
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BalancesAdapter::approve(ALICE, BOB, 60));
+		assert_ok!(BalancesAdapter::transfer_from(&BOB, &ALICE, &BOB, 50));
+		System::assert_has_event(
+			Event::ApprovalChanged {
+				owner: ALICE,
+				spender: BOB,
+				amount: 10,
+			}
+			.into(),
+		);
+		assert_noop!(
+			BalancesAdapter::transfer_from(&BOB, &ALICE, &BOB, 20),
+			<Error<Test>>::ERC20InsufficientAllowance
+		);
+
+		assert_ok!(BalancesAdapter::increase_allowance(ALICE, BOB, 5));
+		assert_ok!(BalancesAdapter::decrease_allowance(ALICE, BOB, 15));
+		assert_noop!(
+			BalancesAdapter::decrease_allowance(ALICE, BOB, 1),
+			<Error<Test>>::ERC20InsufficientAllowance
+		);
+
+		// Infinite allowance is not spent
+		assert_ok!(BalancesAdapter::approve(ALICE, BOB, u128::MAX));
+		assert_ok!(BalancesAdapter::transfer_from(&BOB, &ALICE, &BOB, 50));
+		assert_eq!(allowance(ALICE, BOB), u128::MAX.into());
+		assert_eq!(balance(BOB), 100);
+	});
+}
+
+#[test]
+fn mint_and_burn_change_issuance() {
+	new_test_ext().execute_with(|| {
+		let issuance = BalancesAdapter::total_issuance();
+
+		assert_ok!(BalancesAdapter::mint(&BOB, 100));
+		System::assert_has_event(
+			Event::Minted {
+				to: BOB,
+				amount: 100,
+			}
+			.into(),
+		);
+		assert_eq!(BalancesAdapter::total_issuance(), issuance + 100);
+
+		assert_ok!(BalancesAdapter::burn(&BOB, 40));
+		System::assert_has_event(
+			Event::Burned {
+				from: BOB,
+				amount: 40,
+			}
+			.into(),
+		);
+		assert_eq!(balance(BOB), 60);
+		assert_eq!(BalancesAdapter::total_issuance(), issuance + 60);
+		assert_noop!(
+			BalancesAdapter::burn(&BOB, 61),
+			<Error<Test>>::ERC20InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn sudo_key_has_implicit_permissions_except_explicit_ones() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BalancesAdapter::check_root(&sudo()),
+			<Error<Test>>::OwnerNotFound
+		);
+
+		set_sudo_key();
+		assert_ok!(BalancesAdapter::check_root(&sudo()));
+		assert_noop!(
+			BalancesAdapter::check_root(&ALICE),
+			<Error<Test>>::OwnableUnauthorizedAccount
+		);
+		assert_ok!(BalancesAdapter::check_account_permissions(
+			&sudo(),
+			AccountPermissions::MINT
+		));
+		assert_noop!(
+			BalancesAdapter::check_account_permissions(&sudo(), AccountPermissions::BURN_ANY),
+			<Error<Test>>::UnauthorizedAccount
+		);
+
+		BalancesAdapter::set_account_permissions(&ALICE, AccountPermissions::BURN_ANY);
+		assert_ok!(BalancesAdapter::check_account_permissions(
+			&ALICE,
+			AccountPermissions::BURN_ANY
+		));
+		assert_noop!(
+			BalancesAdapter::check_account_permissions(&ALICE, AccountPermissions::MINT),
+			<Error<Test>>::UnauthorizedAccount
+		);
+
+		BalancesAdapter::set_account_permissions(&ALICE, AccountPermissions::empty());
+		assert_eq!(
+			BalancesAdapter::permissions_of(&ALICE),
+			AccountPermissions::empty()
+		);
+	});
+}
+
+#[test]
+fn permit_sets_allowance_once() {
+	new_test_ext().execute_with(|| {
+		let pair = ecdsa::Pair::from_seed(&[7; 32]);
+		let owner = eth_address(&pair);
+
+		assert_ok!(permit(&pair, BOB, 50.into(), 1_000.into()));
+		assert_eq!(allowance(owner, BOB), 50.into());
+		assert_eq!(BalancesAdapter::nonce(&owner), 1.into());
+
+		Timestamp::set_timestamp(1_001_000);
+		assert_noop!(
+			permit(&pair, BOB, 50.into(), 1_000.into()),
+			<Error<Test>>::ERC2612ExpiredSignature
+		);
+	});
+}
+
+#[test]
+fn infinite_permit() {
+	new_test_ext().execute_with(|| {
+		let pair = ecdsa::Pair::from_seed(&[7; 32]);
+		let owner = eth_address(&pair);
+
+		assert_ok!(permit(&pair, BOB, U256::MAX, U256::MAX));
+		// The signed value is verified, and only the allowance is clamped to the maximum balance
+		assert_eq!(allowance(owner, BOB), u128::MAX.into());
+	});
+}
+
+#[test]
+fn only_native_token_transfers_are_checked() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			BalancesAdapter::ensure_transferable(&H160::repeat_byte(1), &ALICE, u128::MAX),
+			None
+		);
+		assert_eq!(
+			BalancesAdapter::ensure_transferable(&CONTRACT, &ALICE, INITIAL_BALANCE),
+			Some(Ok(()))
+		);
+		assert_eq!(
+			BalancesAdapter::ensure_transferable(&CONTRACT, &ALICE, INITIAL_BALANCE + 1),
+			Some(Err(<Error<Test>>::ERC20InsufficientBalance.into()))
+		);
+	});
+}
@@ -2,8 +2,22 @@ use super::*;
 
 bitflags::bitflags! {
 	/// Permissions of an account.
+	///
+	/// Bits are shared with `pallet_evm_assets::types::AccountPermissions`,
+	/// those without a counterpart in the native token are not defined.
+	/// The sudo key implicitly has all of them, except [`AccountPermissions::EXPLICIT`].
 	#[derive(Encode, Decode, MaxEncodedLen, Default, TypeInfo)]
 	pub struct AccountPermissions: u64 {
+		/// Mint new tokens.
 		const MINT = 1;
+		/// Burn tokens of any holder without allowance.
+		const BURN_ANY = 1 << 3;
+		/// Pause and unpause cross-chain transfers of the token.
+		const BRIDGE_OPERATOR = 1 << 5;
 	}
 }
+
+impl AccountPermissions {
+	/// Permissions, which must be granted explicitly, even to the sudo key.
+	pub const EXPLICIT: Self = Self::BURN_ANY;
+}
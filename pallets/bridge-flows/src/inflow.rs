@@ -0,0 +1,131 @@
+//! Flow limits applied to the assets received from other chains through XCM.
+
+use xcm::latest::{
+	AssetId, Error as XcmError, Fungibility, MultiAsset, Result as XcmResult, XcmContext,
+};
+use xcm_executor::{traits::TransactAsset, Assets};
+
+use crate::*;
+
+/// Asset transactor, which counts the assets received from other chains,
+/// either teleported or withdrawn from the sovereign accounts of the chains,
+/// against the flow limits of [`Pallet`]. Other operations are passed to `Inner` as is.
+///
+/// Only assets identified by the address of their contract are counted.
+///
+/// Must wrap all of the transactors of such assets at once: a tuple of transactors checks in
+/// the assets with each of its elements, so wrapping them separately counts the assets twice.
+pub struct LimitInflow<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T: Config, Inner> LimitInflow<T, Inner> {
+	fn ensure_inflow(origin: &MultiLocation, what: &MultiAsset) -> XcmResult {
+		let Some((asset, amount)) = evm_asset(what) else {
+			return Ok(());
+		};
+		<Pallet<T>>::ensure_flow(
+			asset,
+			chain_id_at::<T>(origin),
+			FlowDirection::Inbound,
+			amount,
+		)
+		.map_err(|error| {
+			log::warn!(
+				target: LOG_TARGET,
+				"Rejected {:?} from {:?}: {:?}",
+				what,
+				origin,
+				error
+			);
+			XcmError::NoPermission
+		})
+	}
+
+	fn note_inflow(origin: &MultiLocation, what: &MultiAsset) {
+		let Some((asset, amount)) = evm_asset(what) else {
+			return;
+		};
+		let chain_id = chain_id_at::<T>(origin);
+		if let Err(error) = <Pallet<T>>::note_flow(asset, chain_id, FlowDirection::Inbound, amount)
+		{
+			log::error!(
+				target: LOG_TARGET,
+				"Unable to count {:?} from {:?}: {:?}",
+				what,
+				origin,
+				error
+			);
+		}
+	}
+}
+
+impl<T: Config, Inner: TransactAsset> TransactAsset for LimitInflow<T, Inner> {
+	fn can_check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_in(origin, what, context)?;
+		Self::ensure_inflow(origin, what)
+	}
+
+	fn check_in(origin: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Inner::check_in(origin, what, context);
+		Self::note_inflow(origin, what);
+	}
+
+	fn can_check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &MultiLocation, what: &MultiAsset, context: &XcmContext) {
+		Inner::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		context: Option<&XcmContext>,
+	) -> XcmResult {
+		Inner::deposit_asset(what, who, context)
+	}
+
+	fn withdraw_asset(
+		what: &MultiAsset,
+		who: &MultiLocation,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<Assets, XcmError> {
+		// Assets reserve-transferred back by the chain are withdrawn from its sovereign account
+		let from_chain = chain_id_at::<T>(who).is_some();
+		if from_chain {
+			Self::ensure_inflow(who, what)?;
+		}
+		let assets = Inner::withdraw_asset(what, who, maybe_context)?;
+		if from_chain {
+			Self::note_inflow(who, what);
+		}
+		Ok(assets)
+	}
+
+	fn internal_transfer_asset(
+		what: &MultiAsset,
+		from: &MultiLocation,
+		to: &MultiLocation,
+		context: &XcmContext,
+	) -> Result<Assets, XcmError> {
+		Inner::internal_transfer_asset(what, from, to, context)
+	}
+}
+
+fn chain_id_at<T: Config>(location: &MultiLocation) -> Option<ChainId> {
+	T::ChainIds::try_convert(*location).ok()
+}
+
+/// Returns the address of the asset contract and the amount.
+fn evm_asset(what: &MultiAsset) -> Option<(H160, u128)> {
+	match what {
+		MultiAsset {
+			id: AssetId::Concrete(location),
+			fun: Fungibility::Fungible(amount),
+		} => match location.last() {
+			Some(Junction::AccountKey20 { key, .. }) => Some((H160(*key), *amount)),
+			_ => None,
+		},
+		_ => None,
+	}
+}
@@ -0,0 +1,289 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use pallet_chain_registry::ChainId;
+use sp_core::H160;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AtLeast32BitUnsigned, TryConvert, UniqueSaturatedInto, Zero},
+	Rounding,
+};
+use sp_std::{iter, marker::PhantomData, prelude::*};
+use xcm::latest::{Junction, MultiLocation};
+
+pub mod inflow;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub(crate) const LOG_TARGET: &str = "runtime::bridge-flows";
+
+/// Cap on the amount of an asset moved across chains in one direction within a period.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct FlowLimit<BlockNumber> {
+	/// Maximum amount moved within the period.
+	pub amount: u128,
+	/// Number of blocks, over which the amount is measured.
+	pub period: BlockNumber,
+}
+
+/// Transfers, which a [`FlowLimit`] applies to.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum FlowScope {
+	/// Transfers to or from any chain.
+	Total,
+	/// Transfers to or from a single chain.
+	Chain(ChainId),
+}
+
+/// Direction, in which an asset is moved.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum FlowDirection {
+	/// From this chain to another one.
+	Outbound,
+	/// From another chain to this one.
+	Inbound,
+}
+
+/// Amount of an asset recently moved across chains.
+///
+/// The amount is released linearly over the period of the limit,
+/// so the limit is applied to a rolling window rather than to fixed periods.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Flow<BlockNumber> {
+	/// Amount counted against the limit at the time of the update.
+	pub amount: u128,
+	/// Block of the last update.
+	pub updated: BlockNumber,
+}
+
+impl<BlockNumber: AtLeast32BitUnsigned + Copy> Flow<BlockNumber> {
+	/// Returns the amount still counted against the limit at the block `now`.
+	fn amount_at(&self, limit: &FlowLimit<BlockNumber>, now: BlockNumber) -> u128 {
+		let elapsed: u128 = now.saturating_sub(self.updated).unique_saturated_into();
+		let period: u128 = limit.period.unique_saturated_into();
+		if elapsed >= period {
+			return 0;
+		}
+		let released =
+			multiply_by_rational_with_rounding(limit.amount, elapsed, period, Rounding::Down)
+				.unwrap_or(limit.amount);
+		self.amount.saturating_sub(released)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_system::pallet_prelude::*;
+
+	use super::*;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The period of a flow limit must not be zero.
+		InvalidFlowLimit,
+		/// Bridging of the asset is paused.
+		BridgingPaused,
+		/// The amount exceeds the flow limit of the asset.
+		FlowLimitExceeded,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A flow limit of an asset was set or removed.
+		FlowLimitChanged {
+			asset: H160,
+			scope: FlowScope,
+			limit: Option<FlowLimit<BlockNumberFor<T>>>,
+		},
+		/// Cross-chain transfers of an asset were paused.
+		BridgingPaused { asset: H160 },
+		/// Cross-chain transfers of an asset were resumed.
+		BridgingUnpaused { asset: H160 },
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may manage the flow limits and pause bridging.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Chains registered at the locations, the inbound flows are counted per chain.
+		type ChainIds: TryConvert<MultiLocation, ChainId>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	/// Limits on the amounts of assets moved across chains, applied to each direction separately.
+	pub(super) type FlowLimits<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		H160,
+		Twox64Concat,
+		FlowScope,
+		FlowLimit<BlockNumberFor<T>>,
+	>;
+
+	#[pallet::storage]
+	/// Amounts of assets recently moved across chains, counted against [`FlowLimits`].
+	pub(super) type Flows<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, H160>,
+			NMapKey<Twox64Concat, FlowScope>,
+			NMapKey<Twox64Concat, FlowDirection>,
+		),
+		Flow<BlockNumberFor<T>>,
+	>;
+
+	#[pallet::storage]
+	/// Assets, whose cross-chain transfers are paused.
+	pub(super) type PausedAssets<T: Config> = StorageMap<_, Blake2_128Concat, H160, ()>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set or remove the limit on the amount of an asset moved across chains within a period.
+		///
+		/// The limit is applied to outbound and inbound transfers separately.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().writes(3))]
+		pub fn set_flow_limit(
+			origin: OriginFor<T>,
+			asset: H160,
+			scope: FlowScope,
+			limit: Option<FlowLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			match limit {
+				Some(limit) => {
+					ensure!(!limit.period.is_zero(), <Error<T>>::InvalidFlowLimit);
+					<FlowLimits<T>>::insert(asset, scope, limit);
+				}
+				None => {
+					<FlowLimits<T>>::remove(asset, scope);
+					<Flows<T>>::remove((asset, scope, FlowDirection::Outbound));
+					<Flows<T>>::remove((asset, scope, FlowDirection::Inbound));
+				}
+			}
+			Self::deposit_event(Event::FlowLimitChanged {
+				asset,
+				scope,
+				limit,
+			});
+			Ok(())
+		}
+
+		/// Pause cross-chain transfers of an asset in both directions.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn pause_bridging(origin: OriginFor<T>, asset: H160) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::set_bridging_paused(asset, true);
+			Ok(())
+		}
+
+		/// Resume cross-chain transfers of an asset.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn unpause_bridging(origin: OriginFor<T>, asset: H160) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::set_bridging_paused(asset, false);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn is_bridging_paused(asset: &H160) -> bool {
+		<PausedAssets<T>>::contains_key(asset)
+	}
+
+	/// Pauses or resumes cross-chain transfers of the asset in both directions.
+	///
+	/// Used by the `ManagerOrigin`, and by the asset pallets on behalf of the asset operators.
+	pub fn set_bridging_paused(asset: H160, paused: bool) {
+		if paused == Self::is_bridging_paused(&asset) {
+			return;
+		}
+
+		if paused {
+			<PausedAssets<T>>::insert(asset, ());
+			Self::deposit_event(Event::BridgingPaused { asset });
+		} else {
+			<PausedAssets<T>>::remove(asset);
+			Self::deposit_event(Event::BridgingUnpaused { asset });
+		}
+	}
+
+	/// Checks that the amount of the asset may be moved to or from the chain,
+	/// without counting it against the flow limits.
+	pub fn ensure_flow(
+		asset: H160,
+		chain_id: Option<ChainId>,
+		direction: FlowDirection,
+		amount: u128,
+	) -> DispatchResult {
+		Self::checked_flows(asset, chain_id, direction, amount).map(|_| ())
+	}
+
+	/// Counts the amount of the asset moved to or from the chain against the flow limits.
+	///
+	/// Fails if bridging of the asset is paused, or if any of the limits would be exceeded.
+	pub fn note_flow(
+		asset: H160,
+		chain_id: Option<ChainId>,
+		direction: FlowDirection,
+		amount: u128,
+	) -> DispatchResult {
+		for (scope, flow) in Self::checked_flows(asset, chain_id, direction, amount)? {
+			<Flows<T>>::insert((asset, scope, direction), flow);
+		}
+		Ok(())
+	}
+
+	fn checked_flows(
+		asset: H160,
+		chain_id: Option<ChainId>,
+		direction: FlowDirection,
+		amount: u128,
+	) -> Result<Vec<(FlowScope, Flow<BlockNumberFor<T>>)>, DispatchError> {
+		ensure!(
+			!Self::is_bridging_paused(&asset),
+			<Error<T>>::BridgingPaused
+		);
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let mut flows = Vec::new();
+		for scope in iter::once(FlowScope::Total).chain(chain_id.map(FlowScope::Chain)) {
+			let Some(limit) = <FlowLimits<T>>::get(asset, scope) else {
+				continue;
+			};
+			let counted = <Flows<T>>::get((asset, scope, direction))
+				.map_or(0, |flow| flow.amount_at(&limit, now));
+			let counted = counted
+				.checked_add(amount)
+				.filter(|counted| *counted <= limit.amount)
+				.ok_or(<Error<T>>::FlowLimitExceeded)?;
+			flows.push((
+				scope,
+				Flow {
+					amount: counted,
+					updated: now,
+				},
+			));
+		}
+		Ok(flows)
+	}
+}
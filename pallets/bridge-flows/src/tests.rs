@@ -0,0 +1,108 @@
+use frame_support::{assert_noop, assert_ok};
+
+use crate::{mock::*, *};
+
+#[test]
+fn flow_limit_is_released_over_period() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BridgeFlows::set_flow_limit(
+				RuntimeOrigin::root(),
+				ASSET,
+				FlowScope::Total,
+				Some(FlowLimit {
+					amount: 100,
+					period: 0
+				})
+			),
+			<Error<Test>>::InvalidFlowLimit
+		);
+		assert_ok!(BridgeFlows::set_flow_limit(
+			RuntimeOrigin::root(),
+			ASSET,
+			FlowScope::Total,
+			Some(FlowLimit {
+				amount: 100,
+				period: 10
+			})
+		));
+
+		assert_ok!(BridgeFlows::note_flow(
+			ASSET,
+			Some(PARA),
+			FlowDirection::Outbound,
+			100
+		));
+		assert_noop!(
+			BridgeFlows::note_flow(ASSET, Some(PARA), FlowDirection::Outbound, 1),
+			<Error<Test>>::FlowLimitExceeded
+		);
+		// Directions are limited separately
+		assert_ok!(BridgeFlows::ensure_flow(
+			ASSET,
+			Some(PARA),
+			FlowDirection::Inbound,
+			100
+		));
+
+		// Half of the period releases half of the limit
+		System::set_block_number(6);
+		assert_ok!(BridgeFlows::note_flow(
+			ASSET,
+			None,
+			FlowDirection::Outbound,
+			50
+		));
+		assert_noop!(
+			BridgeFlows::note_flow(ASSET, None, FlowDirection::Outbound, 1),
+			<Error<Test>>::FlowLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn chain_flow_limit_applies_to_its_chain() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeFlows::set_flow_limit(
+			RuntimeOrigin::root(),
+			ASSET,
+			FlowScope::Chain(PARA),
+			Some(FlowLimit {
+				amount: 10,
+				period: 10
+			})
+		));
+
+		assert_noop!(
+			BridgeFlows::note_flow(ASSET, Some(PARA), FlowDirection::Outbound, 11),
+			<Error<Test>>::FlowLimitExceeded
+		);
+		assert_ok!(BridgeFlows::note_flow(
+			ASSET,
+			Some(RELAY),
+			FlowDirection::Outbound,
+			11
+		));
+	});
+}
+
+#[test]
+fn paused_bridging_rejects_flows() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BridgeFlows::pause_bridging(RuntimeOrigin::root(), ASSET));
+		System::assert_last_event(Event::BridgingPaused { asset: ASSET }.into());
+		assert!(BridgeFlows::is_bridging_paused(&ASSET));
+		assert_noop!(
+			BridgeFlows::ensure_flow(ASSET, None, FlowDirection::Inbound, 1),
+			<Error<Test>>::BridgingPaused
+		);
+
+		assert_ok!(BridgeFlows::unpause_bridging(RuntimeOrigin::root(), ASSET));
+		assert_ok!(BridgeFlows::ensure_flow(
+			ASSET,
+			None,
+			FlowDirection::Inbound,
+			1
+		));
+	});
+}
@@ -0,0 +1,479 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::DispatchResult, ensure, pallet_prelude::*, traits::OnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use sp_core::H160;
+use sp_runtime::traits::TryConvert;
+use sp_std::{boxed::Box, marker::PhantomData, prelude::*};
+use xcm::{
+	latest::{Junction, Junctions, MultiLocation},
+	VersionedMultiLocation,
+};
+
+pub mod migration;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub(crate) const LOG_TARGET: &str = "runtime::chain-registry";
+
+/// EVM chain id.
+pub type ChainId = u64;
+
+/// Destination of cross-chain transfers.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct ChainInfo {
+	/// Location of the chain, relative to this one.
+	pub location: MultiLocation,
+	/// Transfers to disabled chains are rejected.
+	pub enabled: bool,
+	/// Whether all assets may be sent to the chain, or only the allowed ones.
+	pub all_assets_allowed: bool,
+}
+
+/// How an asset is moved to a chain.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo,
+)]
+pub enum TransferMode {
+	/// The asset is burned here and minted on the destination, which must be fully trusted.
+	#[default]
+	Teleport,
+	/// The asset is kept on the sovereign account of the destination,
+	/// which mints a derivative backed by this reserve.
+	Reserve,
+}
+
+/// Where and how an asset is transferred.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub struct Destination {
+	/// Id of the chain.
+	pub chain_id: ChainId,
+	/// Location of the chain, relative to this one.
+	pub location: MultiLocation,
+	/// How the asset is moved.
+	pub mode: TransferMode,
+}
+
+/// Checks that the location is supported by `crossChainTransfer`:
+/// the relay chain or one of its parachains.
+pub fn is_supported_location(location: &MultiLocation) -> bool {
+	matches!(
+		(location.parents, location.interior),
+		(1, Junctions::Here)
+			| (1, Junctions::X1(Junction::Parachain(_)))
+			| (0, Junctions::X1(Junction::Parachain(_)))
+	)
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_system::pallet_prelude::*;
+
+	use super::*;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The chain is already registered.
+		ChainAlreadyRegistered,
+		/// The chain is not registered.
+		ChainNotFound,
+		/// Another chain is registered at the location.
+		LocationAlreadyRegistered,
+		/// The location can not be converted to the supported XCM version.
+		BadVersion,
+		/// Only the relay chain and its parachains are supported.
+		UnsupportedLocation,
+		/// The chain was deregistered, and its assets are not cleared yet.
+		ChainNotCleared,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A chain was registered.
+		ChainRegistered {
+			chain_id: ChainId,
+			location: MultiLocation,
+		},
+		/// Location of a chain was changed.
+		ChainLocationUpdated {
+			chain_id: ChainId,
+			location: MultiLocation,
+		},
+		/// A chain was enabled or disabled.
+		ChainEnabledChanged { chain_id: ChainId, enabled: bool },
+		/// A chain started or stopped accepting all assets.
+		AllAssetsAllowedChanged { chain_id: ChainId, allowed: bool },
+		/// An asset was allowed or disallowed to be sent to a chain.
+		AssetAllowedChanged {
+			chain_id: ChainId,
+			asset: H160,
+			allowed: bool,
+		},
+		/// The way an asset is transferred to a chain was changed.
+		TransferModeChanged {
+			chain_id: ChainId,
+			asset: H160,
+			mode: TransferMode,
+		},
+		/// A chain was removed.
+		ChainDeregistered { chain_id: ChainId },
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may manage the chains.
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	/// Registered chains.
+	pub(super) type Chains<T: Config> = StorageMap<_, Twox64Concat, ChainId, ChainInfo>;
+
+	#[pallet::storage]
+	/// Assets, which may be sent to chains not accepting all assets.
+	pub(super) type AllowedAssets<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ChainId, Blake2_128Concat, H160, ()>;
+
+	#[pallet::storage]
+	/// Chains registered at the locations, the reverse of [`Chains`].
+	pub(super) type ChainIds<T: Config> = StorageMap<_, Blake2_128Concat, MultiLocation, ChainId>;
+
+	#[pallet::storage]
+	/// Assets, which are moved to chains in a mode other than the default [`TransferMode::Teleport`].
+	pub(super) type TransferModes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ChainId,
+		Blake2_128Concat,
+		H160,
+		TransferMode,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Deregistered chains, whose [`AllowedAssets`] and [`TransferModes`] are not cleared yet.
+	pub(super) type DeregisteredChains<T: Config> = StorageMap<_, Twox64Concat, ChainId, ()>;
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Chains registered at genesis, accepting all assets.
+		pub chains: Vec<(ChainId, MultiLocation)>,
+		#[serde(skip)]
+		pub _config: PhantomData<T>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for &(chain_id, location) in &self.chains {
+				assert!(
+					is_supported_location(&location),
+					"chain {chain_id} has an unsupported location"
+				);
+				assert!(
+					!<Chains<T>>::contains_key(chain_id) && !<ChainIds<T>>::contains_key(location),
+					"chain {chain_id} is registered twice"
+				);
+				<Pallet<T>>::insert_chain(chain_id, location, true);
+			}
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Clears the allowed assets and the transfer modes of a deregistered chain,
+		/// as many as fit in the block.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// Looking up the chain and forgetting it once it is cleared
+			let base = db_weight.reads_writes(1, 1);
+			let limit = remaining_weight.saturating_sub(base).ref_time()
+				/ db_weight.writes(1).ref_time().max(1);
+			if remaining_weight.any_lt(base) || limit == 0 {
+				return Weight::zero();
+			}
+
+			let Some(chain_id) = <DeregisteredChains<T>>::iter_keys().next() else {
+				return db_weight.reads(1);
+			};
+			let limit = limit.min(u32::MAX as u64) as u32;
+			let assets = <AllowedAssets<T>>::clear_prefix(chain_id, limit, None);
+			let mut weight = base
+				.saturating_add(db_weight.reads_writes(assets.loops as u64, assets.unique as u64));
+			let remaining = limit.saturating_sub(assets.loops);
+			if assets.maybe_cursor.is_some() || remaining == 0 {
+				return weight;
+			}
+
+			let modes = <TransferModes<T>>::clear_prefix(chain_id, remaining, None);
+			weight = weight
+				.saturating_add(db_weight.reads_writes(modes.loops as u64, modes.unique as u64));
+			if modes.maybe_cursor.is_none() {
+				<DeregisteredChains<T>>::remove(chain_id);
+			}
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a chain. It is enabled right away.
+		///
+		/// A deregistered chain can only be registered again once its assets are cleared.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 2))]
+		pub fn register_chain(
+			origin: OriginFor<T>,
+			chain_id: ChainId,
+			location: Box<VersionedMultiLocation>,
+			all_assets_allowed: bool,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				!<Chains<T>>::contains_key(chain_id),
+				<Error<T>>::ChainAlreadyRegistered
+			);
+			ensure!(
+				!<DeregisteredChains<T>>::contains_key(chain_id),
+				<Error<T>>::ChainNotCleared
+			);
+			let location = Self::checked_location(*location)?;
+			ensure!(
+				!<ChainIds<T>>::contains_key(location),
+				<Error<T>>::LocationAlreadyRegistered
+			);
+
+			Self::insert_chain(chain_id, location, all_assets_allowed);
+			Ok(())
+		}
+
+		/// Change the location of a chain.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(2, 3))]
+		pub fn update_location(
+			origin: OriginFor<T>,
+			chain_id: ChainId,
+			location: Box<VersionedMultiLocation>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			let location = Self::checked_location(*location)?;
+			ensure!(
+				<ChainIds<T>>::get(location).map_or(true, |id| id == chain_id),
+				<Error<T>>::LocationAlreadyRegistered
+			);
+
+			let mut previous = location;
+			Self::mutate_chain(chain_id, |chain| {
+				previous = chain.location;
+				chain.location = location;
+			})?;
+			<ChainIds<T>>::remove(previous);
+			<ChainIds<T>>::insert(location, chain_id);
+			Self::deposit_event(Event::ChainLocationUpdated { chain_id, location });
+			Ok(())
+		}
+
+		/// Enable or disable transfers to a chain.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_enabled(
+			origin: OriginFor<T>,
+			chain_id: ChainId,
+			enabled: bool,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::mutate_chain(chain_id, |chain| chain.enabled = enabled)?;
+			Self::deposit_event(Event::ChainEnabledChanged { chain_id, enabled });
+			Ok(())
+		}
+
+		/// Accept all assets on a chain, or only the allowed ones.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_all_assets_allowed(
+			origin: OriginFor<T>,
+			chain_id: ChainId,
+			allowed: bool,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Self::mutate_chain(chain_id, |chain| chain.all_assets_allowed = allowed)?;
+			Self::deposit_event(Event::AllAssetsAllowedChanged { chain_id, allowed });
+			Ok(())
+		}
+
+		/// Allow or disallow sending an asset, identified by its contract address, to a chain.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_asset_allowed(
+			origin: OriginFor<T>,
+			chain_id: ChainId,
+			asset: H160,
+			allowed: bool,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				<Chains<T>>::contains_key(chain_id),
+				<Error<T>>::ChainNotFound
+			);
+
+			if allowed {
+				<AllowedAssets<T>>::insert(chain_id, asset, ());
+			} else {
+				<AllowedAssets<T>>::remove(chain_id, asset);
+			}
+			Self::deposit_event(Event::AssetAllowedChanged {
+				chain_id,
+				asset,
+				allowed,
+			});
+			Ok(())
+		}
+
+		/// Remove a chain along with its allowed assets and transfer modes.
+		///
+		/// The assets are cleared in `on_idle`, the chain is not used for transfers meanwhile.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 3))]
+		pub fn deregister_chain(origin: OriginFor<T>, chain_id: ChainId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			let chain = <Chains<T>>::take(chain_id).ok_or(<Error<T>>::ChainNotFound)?;
+
+			<ChainIds<T>>::remove(chain.location);
+			<DeregisteredChains<T>>::insert(chain_id, ());
+			Self::deposit_event(Event::ChainDeregistered { chain_id });
+			Ok(())
+		}
+
+		/// Choose whether an asset is teleported or reserve-transferred to a chain.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+		pub fn set_transfer_mode(
+			origin: OriginFor<T>,
+			chain_id: ChainId,
+			asset: H160,
+			mode: TransferMode,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				<Chains<T>>::contains_key(chain_id),
+				<Error<T>>::ChainNotFound
+			);
+
+			if mode == TransferMode::default() {
+				<TransferModes<T>>::remove(chain_id, asset);
+			} else {
+				<TransferModes<T>>::insert(chain_id, asset, mode);
+			}
+			Self::deposit_event(Event::TransferModeChanged {
+				chain_id,
+				asset,
+				mode,
+			});
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn chain(chain_id: ChainId) -> Option<ChainInfo> {
+		<Chains<T>>::get(chain_id)
+	}
+
+	pub fn is_asset_allowed(chain_id: ChainId, asset: &H160) -> bool {
+		<AllowedAssets<T>>::contains_key(chain_id, asset)
+	}
+
+	/// Returns where and how the asset is transferred to the chain,
+	/// if it is enabled and accepts the asset.
+	pub fn destination(chain_id: ChainId, asset: &H160) -> Option<Destination> {
+		let chain = <Chains<T>>::get(chain_id)?;
+		if !chain.enabled || !(chain.all_assets_allowed || Self::is_asset_allowed(chain_id, asset))
+		{
+			return None;
+		}
+		Some(Destination {
+			chain_id,
+			location: chain.location,
+			mode: <TransferModes<T>>::get(chain_id, asset),
+		})
+	}
+
+	/// Returns how the asset is moved between this chain and the chain at the location.
+	/// Unregistered chains only take part in teleports.
+	pub fn transfer_mode_at(location: &MultiLocation, asset: &H160) -> TransferMode {
+		<ChainIds<T>>::get(location)
+			.map(|chain_id| <TransferModes<T>>::get(chain_id, asset))
+			.unwrap_or_default()
+	}
+
+	/// Returns the chain registered at the location.
+	pub fn chain_id_at(location: &MultiLocation) -> Option<ChainId> {
+		<ChainIds<T>>::get(location)
+	}
+
+	fn checked_location(location: VersionedMultiLocation) -> Result<MultiLocation, DispatchError> {
+		let location: MultiLocation = location.try_into().map_err(|()| <Error<T>>::BadVersion)?;
+		ensure!(
+			is_supported_location(&location),
+			<Error<T>>::UnsupportedLocation
+		);
+		Ok(location)
+	}
+
+	pub(crate) fn insert_chain(
+		chain_id: ChainId,
+		location: MultiLocation,
+		all_assets_allowed: bool,
+	) {
+		<Chains<T>>::insert(
+			chain_id,
+			ChainInfo {
+				location,
+				enabled: true,
+				all_assets_allowed,
+			},
+		);
+		<ChainIds<T>>::insert(location, chain_id);
+		Self::deposit_event(Event::ChainRegistered { chain_id, location });
+	}
+
+	fn mutate_chain(chain_id: ChainId, f: impl FnOnce(&mut ChainInfo)) -> DispatchResult {
+		<Chains<T>>::try_mutate(chain_id, |chain| {
+			let chain = chain.as_mut().ok_or(<Error<T>>::ChainNotFound)?;
+			f(chain);
+			Ok(())
+		})
+	}
+}
+
+impl<T: Config> TryConvert<(ChainId, H160), Destination> for Pallet<T> {
+	fn try_convert((chain_id, asset): (ChainId, H160)) -> Result<Destination, (ChainId, H160)> {
+		Self::destination(chain_id, &asset).ok_or((chain_id, asset))
+	}
+}
+
+impl<T: Config> TryConvert<MultiLocation, ChainId> for Pallet<T> {
+	fn try_convert(location: MultiLocation) -> Result<ChainId, MultiLocation> {
+		Self::chain_id_at(&location).ok_or(location)
+	}
+}
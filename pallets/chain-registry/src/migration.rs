@@ -0,0 +1,39 @@
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+use crate::*;
+
+/// Registers the given chains when the pallet is first deployed to a running chain,
+/// to keep transfers to the chains of the former static `ChainLocator` working.
+/// Networks launched from a new genesis take the chains from the genesis config instead,
+/// the migration is a no-op there, since genesis sets the current storage version.
+pub struct SeedChains<T, C>(PhantomData<(T, C)>);
+
+impl<T, C> OnRuntimeUpgrade for SeedChains<T, C>
+where
+	T: Config,
+	C: Get<Vec<(ChainId, MultiLocation)>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		if <Pallet<T>>::on_chain_storage_version() != 0 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let chains = C::get();
+		let count = chains.len() as u64;
+		for (chain_id, location) in chains {
+			if !is_supported_location(&location) {
+				log::error!(
+					target: LOG_TARGET,
+					"Skipped seeding chain {} with unsupported location {:?}",
+					chain_id,
+					location
+				);
+				continue;
+			}
+			<Pallet<T>>::insert_chain(chain_id, location, true);
+		}
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1, 1 + count)
+	}
+}
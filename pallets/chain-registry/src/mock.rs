@@ -0,0 +1,81 @@
+use frame_support::{
+	construct_runtime,
+	traits::{ConstU32, ConstU64, Everything},
+};
+use frame_system::EnsureRoot;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use xcm::latest::prelude::*;
+
+use crate::{self as pallet_chain_registry, *};
+
+pub type AccountId = AccountId32;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		ChainRegistry: pallet_chain_registry,
+	}
+);
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_chain_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
+
+pub const RELAY: ChainId = 1;
+pub const PARA: ChainId = 2;
+pub const ASSET: H160 = H160([0x10; 20]);
+
+pub fn relay() -> MultiLocation {
+	MultiLocation::parent()
+}
+
+pub fn para(id: u32) -> MultiLocation {
+	MultiLocation::new(0, X1(Parachain(id)))
+}
+
+pub fn new_test_ext(chains: Vec<(ChainId, MultiLocation)>) -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig {
+		chain_registry: pallet_chain_registry::GenesisConfig {
+			chains,
+			..Default::default()
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,220 @@
+use frame_support::{
+	assert_noop, assert_ok, parameter_types,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use xcm::{
+	latest::{
+		Junction::{PalletInstance, Parachain},
+		Junctions::X2,
+	},
+	VersionedMultiLocation,
+};
+
+use crate::{mock::*, *};
+
+parameter_types! {
+	pub KnownChains: Vec<(ChainId, MultiLocation)> = vec![(RELAY, relay()), (PARA, para(2000))];
+}
+
+fn register(chain_id: ChainId, location: MultiLocation, all_assets_allowed: bool) {
+	assert_ok!(ChainRegistry::register_chain(
+		RuntimeOrigin::root(),
+		chain_id,
+		Box::new(VersionedMultiLocation::V3(location)),
+		all_assets_allowed,
+	));
+}
+
+#[test]
+fn genesis_chains_are_registered() {
+	new_test_ext(vec![(RELAY, relay())]).execute_with(|| {
+		assert_eq!(
+			ChainRegistry::chain(RELAY),
+			Some(ChainInfo {
+				location: relay(),
+				enabled: true,
+				all_assets_allowed: true,
+			})
+		);
+		assert_eq!(ChainRegistry::chain_id_at(&relay()), Some(RELAY));
+		assert_eq!(ChainRegistry::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn seed_chains_only_runs_on_first_deployment() {
+	new_test_ext(vec![]).execute_with(|| {
+		migration::SeedChains::<Test, KnownChains>::on_runtime_upgrade();
+		assert_eq!(ChainRegistry::chain(RELAY), None);
+
+		StorageVersion::new(0).put::<ChainRegistry>();
+		migration::SeedChains::<Test, KnownChains>::on_runtime_upgrade();
+		assert_eq!(ChainRegistry::chain_id_at(&relay()), Some(RELAY));
+		assert_eq!(ChainRegistry::chain_id_at(&para(2000)), Some(PARA));
+		assert_eq!(ChainRegistry::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn registration_is_managed_by_manager_origin() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert_noop!(
+			ChainRegistry::register_chain(
+				RuntimeOrigin::signed(AccountId::new([1; 32])),
+				RELAY,
+				Box::new(VersionedMultiLocation::V3(relay())),
+				true,
+			),
+			DispatchError::BadOrigin
+		);
+
+		register(RELAY, relay(), true);
+		System::assert_last_event(
+			Event::ChainRegistered {
+				chain_id: RELAY,
+				location: relay(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			ChainRegistry::register_chain(
+				RuntimeOrigin::root(),
+				RELAY,
+				Box::new(VersionedMultiLocation::V3(para(2000))),
+				true,
+			),
+			<Error<Test>>::ChainAlreadyRegistered
+		);
+		assert_noop!(
+			ChainRegistry::register_chain(
+				RuntimeOrigin::root(),
+				PARA,
+				Box::new(VersionedMultiLocation::V3(relay())),
+				true,
+			),
+			<Error<Test>>::LocationAlreadyRegistered
+		);
+		assert_noop!(
+			ChainRegistry::register_chain(
+				RuntimeOrigin::root(),
+				PARA,
+				Box::new(VersionedMultiLocation::V3(MultiLocation::new(
+					1,
+					X2(Parachain(2000), PalletInstance(1))
+				))),
+				true,
+			),
+			<Error<Test>>::UnsupportedLocation
+		);
+	});
+}
+
+#[test]
+fn location_is_updated_in_both_directions() {
+	new_test_ext(vec![(PARA, para(2000))]).execute_with(|| {
+		assert_ok!(ChainRegistry::update_location(
+			RuntimeOrigin::root(),
+			PARA,
+			Box::new(VersionedMultiLocation::V3(para(2001))),
+		));
+		assert_eq!(ChainRegistry::chain(PARA).unwrap().location, para(2001));
+		assert_eq!(ChainRegistry::chain_id_at(&para(2000)), None);
+		assert_eq!(ChainRegistry::chain_id_at(&para(2001)), Some(PARA));
+	});
+}
+
+#[test]
+fn destination_respects_chain_settings() {
+	new_test_ext(vec![(PARA, para(2000))]).execute_with(|| {
+		assert_eq!(
+			ChainRegistry::destination(PARA, &ASSET),
+			Some(Destination {
+				chain_id: PARA,
+				location: para(2000),
+				mode: TransferMode::Teleport,
+			})
+		);
+
+		assert_ok!(ChainRegistry::set_transfer_mode(
+			RuntimeOrigin::root(),
+			PARA,
+			ASSET,
+			TransferMode::Reserve
+		));
+		assert_eq!(
+			ChainRegistry::destination(PARA, &ASSET).map(|destination| destination.mode),
+			Some(TransferMode::Reserve)
+		);
+		assert_eq!(
+			ChainRegistry::transfer_mode_at(&para(2000), &ASSET),
+			TransferMode::Reserve
+		);
+
+		assert_ok!(ChainRegistry::set_all_assets_allowed(
+			RuntimeOrigin::root(),
+			PARA,
+			false
+		));
+		assert_eq!(ChainRegistry::destination(PARA, &ASSET), None);
+		assert_ok!(ChainRegistry::set_asset_allowed(
+			RuntimeOrigin::root(),
+			PARA,
+			ASSET,
+			true
+		));
+		assert!(ChainRegistry::destination(PARA, &ASSET).is_some());
+
+		assert_ok!(ChainRegistry::set_enabled(
+			RuntimeOrigin::root(),
+			PARA,
+			false
+		));
+		assert_eq!(ChainRegistry::destination(PARA, &ASSET), None);
+	});
+}
+
+#[test]
+fn deregistered_chain_is_cleared_on_idle() {
+	new_test_ext(vec![(PARA, para(2000))]).execute_with(|| {
+		for byte in 0..3 {
+			assert_ok!(ChainRegistry::set_asset_allowed(
+				RuntimeOrigin::root(),
+				PARA,
+				H160::repeat_byte(byte),
+				true
+			));
+		}
+		assert_ok!(ChainRegistry::set_transfer_mode(
+			RuntimeOrigin::root(),
+			PARA,
+			ASSET,
+			TransferMode::Reserve
+		));
+
+		assert_ok!(ChainRegistry::deregister_chain(RuntimeOrigin::root(), PARA));
+		assert_eq!(ChainRegistry::chain(PARA), None);
+		assert_eq!(ChainRegistry::chain_id_at(&para(2000)), None);
+		assert_noop!(
+			ChainRegistry::register_chain(
+				RuntimeOrigin::root(),
+				PARA,
+				Box::new(VersionedMultiLocation::V3(para(2000))),
+				true,
+			),
+			<Error<Test>>::ChainNotCleared
+		);
+
+		ChainRegistry::on_idle(1, Weight::MAX);
+		assert!(!ChainRegistry::is_asset_allowed(
+			PARA,
+			&H160::repeat_byte(0)
+		));
+		assert!(!<TransferModes<Test>>::contains_key(PARA, ASSET));
+		assert!(!<DeregisteredChains<Test>>::contains_key(PARA));
+
+		// Cleared chains start over
+		register(PARA, para(2000), false);
+		assert_eq!(ChainRegistry::destination(PARA, &ASSET), None);
+	});
+}
@@ -0,0 +1,120 @@
+//! Benchmarks of the `ForceOrigin` extrinsics.
+
+use frame_benchmarking::v2::*;
+use sp_runtime::traits::Saturating;
+
+use crate::*;
+
+const OWNER: Address = H160([0x11; 20]);
+const HOLDER: Address = H160([0x22; 20]);
+const AMOUNT: Balance = 1_000_000;
+
+fn force_origin<T: Config>() -> <T as frame_system::Config>::RuntimeOrigin {
+	T::ForceOrigin::try_successful_origin().expect("ForceOrigin has no successful origin")
+}
+
+fn max_metadata<T: Config>() -> Vec<u8> {
+	sp_std::vec![b'A'; T::StringLimit::get() as usize]
+}
+
+fn create<T: Config>(asset_id: AssetId) {
+	let metadata = <Pallet<T>>::bounded_metadata(max_metadata::<T>(), max_metadata::<T>(), 18)
+		.expect("metadata is within the limit");
+	<Pallet<T>>::insert_asset(asset_id, OWNER, metadata);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn force_create() {
+		let name = max_metadata::<T>();
+		let symbol = max_metadata::<T>();
+
+		#[extrinsic_call]
+		_(force_origin::<T>(), 1, OWNER, name, symbol, 18);
+
+		assert!(<Pallet<T>>::asset_exists(1));
+	}
+
+	#[benchmark]
+	fn force_set_metadata() {
+		create::<T>(1);
+		let name = max_metadata::<T>();
+		let symbol = max_metadata::<T>();
+
+		#[extrinsic_call]
+		_(force_origin::<T>(), 1, name, symbol, 6, true);
+
+		assert!(<Metadata<T>>::get(1).expect("asset exists").is_frozen);
+	}
+
+	#[benchmark]
+	fn force_transfer_ownership() {
+		create::<T>(1);
+
+		#[extrinsic_call]
+		_(force_origin::<T>(), 1, HOLDER);
+
+		assert!(<Pallet<T>>::check_owner(&1, &HOLDER).is_ok());
+	}
+
+	#[benchmark]
+	fn force_set_permissions() {
+		create::<T>(1);
+		<Pallet<T>>::configure_minter(&1, &HOLDER, AMOUNT);
+
+		#[extrinsic_call]
+		_(
+			force_origin::<T>(),
+			1,
+			HOLDER,
+			AccountPermissions::all().bits(),
+		);
+
+		assert_eq!(
+			<Pallet<T>>::permissions_of(&1, &HOLDER),
+			AccountPermissions::all()
+		);
+	}
+
+	#[benchmark]
+	fn force_mint() {
+		create::<T>(1);
+		<Pallet<T>>::set_max_supply(&1, AMOUNT).expect("supply is below the cap");
+
+		#[extrinsic_call]
+		_(force_origin::<T>(), 1, HOLDER, AMOUNT);
+
+		assert_eq!(<Pallet<T>>::balance(&1, &HOLDER), AMOUNT);
+	}
+
+	#[benchmark]
+	fn force_burn() {
+		create::<T>(1);
+		<Pallet<T>>::mint(&1, &HOLDER, AMOUNT).expect("asset exists");
+
+		#[extrinsic_call]
+		_(force_origin::<T>(), 1, HOLDER, AMOUNT);
+
+		assert_eq!(<Pallet<T>>::balance(&1, &HOLDER), 0);
+	}
+
+	#[benchmark]
+	fn force_destroy() {
+		let depositor = T::CrossAccountId::from_eth(OWNER).as_sub().clone();
+		T::DepositCurrency::make_free_balance_be(
+			&depositor,
+			T::DepositCurrency::minimum_balance().saturating_add(T::AssetDeposit::get()),
+		);
+		let asset_id =
+			<Pallet<T>>::create_asset(&OWNER, max_metadata::<T>(), max_metadata::<T>(), 18)
+				.expect("deposit is available");
+
+		#[extrinsic_call]
+		_(force_origin::<T>(), asset_id);
+
+		assert!(!<Pallet<T>>::asset_exists(asset_id));
+	}
+}
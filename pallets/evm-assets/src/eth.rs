@@ -5,6 +5,8 @@ use pallet_evm_coder_substrate::{
 	execution::{PreDispatch, Result},
 	frontier_contract,
 };
+use pallet_xcm_transfers::transfer::{self, Beneficiary};
+use staging_xcm::latest::WeightLimit;
 
 use crate::*;
 
@@ -26,6 +28,53 @@ pub enum ERC20Events {
 	},
 }
 
+#[derive(ToLog)]
+pub enum OwnableEvents {
+	OwnershipTransferStarted {
+		#[indexed]
+		previous_owner: Address,
+		#[indexed]
+		new_owner: Address,
+	},
+	OwnershipTransferred {
+		#[indexed]
+		previous_owner: Address,
+		#[indexed]
+		new_owner: Address,
+	},
+}
+
+#[derive(ToLog)]
+pub enum ComplianceEvents {
+	Paused {
+		account: Address,
+	},
+	Unpaused {
+		account: Address,
+	},
+	Frozen {
+		#[indexed]
+		account: Address,
+	},
+	Unfrozen {
+		#[indexed]
+		account: Address,
+	},
+}
+
+#[derive(ToLog)]
+pub enum MintingEvents {
+	MinterConfigured {
+		#[indexed]
+		minter: Address,
+		allowance: U256,
+	},
+	MinterRemoved {
+		#[indexed]
+		minter: Address,
+	},
+}
+
 frontier_contract! {
 	macro_rules! FungibleAssetsHandle_result {...}
 	impl<T: Config> Contract for FungibleAssetsHandle<T> {...}
@@ -40,7 +89,8 @@ impl<T: Config> FungibleAssetsHandle<T> {
 
 	fn approve(&mut self, caller: Caller, spender: Address, amount: U256) -> Result<bool> {
 		self.consume_store_writes(1)?;
-		let amount = amount.try_into().map_err(|_| "amount overflow")?;
+		// Amounts above `Balance::MAX` are treated as an infinite approval.
+		let amount = amount.try_into().unwrap_or(Balance::MAX);
 		<Pallet<T>>::approve(self.asset_id(), &caller, &spender, amount, true)
 			.map_err(dispatch_to_evm::<T>)?;
 		Ok(true)
@@ -115,8 +165,17 @@ impl<T: Config> FungibleAssetsHandle<T> {
 		self.consume_store_reads(3)?;
 		self.consume_store_writes(3)?;
 		let value = value.try_into().map_err(|_| "value overflow")?;
-		<Pallet<T>>::spend_allowance(self.asset_id(), &account, &caller, value)
-			.map_err(dispatch_to_evm::<T>)?;
+		// Accounts with the BURN_ANY permission don't need an allowance.
+		if <Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::BURN_ANY,
+		)
+		.is_err()
+		{
+			<Pallet<T>>::spend_allowance(self.asset_id(), &account, &caller, value)
+				.map_err(dispatch_to_evm::<T>)?;
+		}
 		<Pallet<T>>::burn(self.asset_id(), &account, value).map_err(dispatch_to_evm::<T>)
 	}
 }
@@ -124,23 +183,104 @@ impl<T: Config> FungibleAssetsHandle<T> {
 #[solidity_interface(name = ERC20Mintable, is(ERC20), enum(derive(PreDispatch)), enum_attr(weight))]
 impl<T: Config> FungibleAssetsHandle<T> {
 	pub fn mint(&mut self, caller: Caller, to: Address, amount: U256) -> Result<()> {
-		self.consume_store_reads(3)?;
-		self.consume_store_writes(2)?;
+		self.consume_store_reads(5)?;
+		self.consume_store_writes(3)?;
 
 		<Pallet<T>>::check_account_permissions(self.asset_id(), &caller, AccountPermissions::MINT)
 			.map_err(dispatch_to_evm::<T>)?;
 
 		let amount = amount.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::spend_minter_allowance(self.asset_id(), &caller, amount)
+			.map_err(dispatch_to_evm::<T>)?;
 		<Pallet<T>>::mint(self.asset_id(), &to, amount).map_err(dispatch_to_evm::<T>)
 	}
 }
 
-#[solidity_interface(name = XcmExtensions, is(ERC20), enum(derive(PreDispatch)), enum_attr(weight))]
+// The receiver is `bytes32`, addresses are left-padded with zeros.
+// The message id is the topic of the XCM message sent to the destination.
+// Events are emitted by the called contract, other assets sent along are reported
+// by `CrossChainAssetTransferInitiated`.
+#[derive(ToLog)]
+pub enum XcmEvents {
+	CrossChainTransferInitiated {
+		chain_id: u64,
+		receiver: H256,
+		amount: U256,
+		#[indexed]
+		message_id: H256,
+	},
+	CrossChainAssetTransferInitiated {
+		#[indexed]
+		asset: Address,
+		chain_id: u64,
+		receiver: H256,
+		amount: U256,
+		#[indexed]
+		message_id: H256,
+	},
+}
+
 impl<T: Config> FungibleAssetsHandle<T>
 where
 	<T as frame_system::Config>::RuntimeOrigin: From<EthereumOrigin>,
 {
-	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets() + T::DbWeight::get().reads(2_u64))]
+	fn transfer_to(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		beneficiary: Beneficiary,
+		assets: Vec<(Address, U256)>,
+		fee_asset: Address,
+		weight_limit: WeightLimit,
+	) -> Result<()> {
+		let assets = assets
+			.into_iter()
+			.map(|(asset, amount)| Ok((asset, amount.try_into().map_err(|_| "value overflow")?)))
+			.collect::<Result<Vec<(Address, u128)>>>()?;
+
+		let message_id = transfer::transfer::<T, <T as Config>::ChainLocator>(
+			EthereumOrigin::EthereumTransaction(caller).into(),
+			caller,
+			chain_id,
+			&assets,
+			fee_asset,
+			weight_limit,
+			beneficiary,
+		)?;
+
+		let contract = <Pallet<T>>::asset_id_to_address(self.asset_id());
+		for (asset, amount) in assets {
+			let receiver = beneficiary.to_bytes32();
+			let amount = amount.into();
+			let message_id = message_id.into();
+			let event = if asset == contract {
+				XcmEvents::CrossChainTransferInitiated {
+					chain_id,
+					receiver,
+					amount,
+					message_id,
+				}
+			} else {
+				XcmEvents::CrossChainAssetTransferInitiated {
+					asset,
+					chain_id,
+					receiver,
+					amount,
+					message_id,
+				}
+			};
+			<PalletEvm<T>>::deposit_log(event.to_log(contract));
+		}
+		Ok(())
+	}
+}
+
+#[solidity_interface(name = XcmExtensions, is(ERC20), events(XcmEvents), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T>
+where
+	<T as frame_system::Config>::RuntimeOrigin: From<EthereumOrigin>,
+{
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()) + T::DbWeight::get().reads_writes(9_u64, 7_u64))]
 	pub fn cross_chain_transfer(
 		&mut self,
 		caller: Caller,
@@ -148,57 +288,136 @@ where
 		receiver: Address,
 		amount: U256,
 	) -> Result<()> {
-		let amount = amount.try_into().map_err(|_| "value overflow")?;
-		let locator = <T as Config>::ChainLocator::get();
-		let destination = *locator.get(&chain_id).ok_or("chain not found")?;
-		let relay_network = T::UniversalLocation::get()
-			.global_consensus()
-			.map_err(|_| "unable to get global consensus")?;
-		if amount > <Pallet<T>>::balance(self.asset_id(), &caller) {
-			return Err(dispatch_to_evm::<T>(
-				<Error<T>>::ERC20InsufficientBalance.into(),
-			));
-		}
+		let asset = <Pallet<T>>::asset_id_to_address(self.asset_id());
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountKey20(receiver),
+			Vec::from([(asset, amount)]),
+			asset,
+			WeightLimit::Unlimited,
+		)
+	}
 
-		// Determining the asset location relative to the relay.
-		// For relay - 0, for parachains - 1.
-		// Correctness is ensured by the correct configuration of the `ChainLocator`.
-		let parents = match (destination.parent_count(), destination.interior()) {
-			(1, Junctions::Here) | (1, Junctions::X1(Junction::Parachain(_))) => Ok(1),
-			(0, Junctions::X1(Junction::Parachain(_))) => Ok(0),
-			_ => Err("unsupported location pattern"),
-		}?;
-
-		let asset = XcmAsset {
-			id: Location::new(
-				parents,
-				Junction::AccountKey20 {
-					network: Some(relay_network),
-					key: <Pallet<T>>::asset_id_to_address(self.asset_id()).into(),
-				},
-			)
-			.into(),
-			fun: Fungibility::Fungible(amount),
-		};
-
-		let beneficiary = Location::new(
-			0,
-			Junction::AccountKey20 {
-				network: Some(relay_network),
-				key: receiver.into(),
-			},
-		);
-
-		let fee_asset_item = 0;
-		<PalletXcm<T>>::limited_teleport_assets(
-			EthereumOrigin::EthereumTransaction(caller).into(),
-			Box::new(destination.into()),
-			Box::new(beneficiary.into()),
-			Box::new(asset.into()),
-			fee_asset_item,
+	/// Transfer to a Substrate account, e.g. sr25519, on the destination chain.
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()) + T::DbWeight::get().reads_writes(9_u64, 7_u64))]
+	pub fn cross_chain_transfer_to_substrate(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		receiver: H256,
+		amount: U256,
+	) -> Result<()> {
+		let asset = <Pallet<T>>::asset_id_to_address(self.asset_id());
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountId32(receiver),
+			Vec::from([(asset, amount)]),
+			asset,
 			WeightLimit::Unlimited,
 		)
-		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Transfer several assets, identified by the addresses of their contracts,
+	/// paying for the execution on the destination chain with the fee asset.
+	///
+	/// The weight limit is the maximum execution time bought on the destination,
+	/// `uint64` max means no limit.
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()).saturating_mul(assets.len().max(1) as u64) + T::DbWeight::get().reads_writes(4 + 5 * assets.len() as u64, 5 + 2 * assets.len() as u64))]
+	#[solidity(rename_selector = "crossChainTransfer")]
+	pub fn cross_chain_transfer_assets(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		receiver: Address,
+		assets: Vec<(Address, U256)>,
+		fee_asset: Address,
+		weight_limit: u64,
+	) -> Result<()> {
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountKey20(receiver),
+			assets,
+			fee_asset,
+			transfer::weight_limit(weight_limit),
+		)
+	}
+
+	/// Transfer several assets to a Substrate account, e.g. sr25519, on the destination chain.
+	#[weight(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::teleport_assets().max(<<T as pallet_xcm::Config>::WeightInfo as PalletXcmWeightInfo>::reserve_transfer_assets()).saturating_mul(assets.len().max(1) as u64) + T::DbWeight::get().reads_writes(4 + 5 * assets.len() as u64, 5 + 2 * assets.len() as u64))]
+	#[solidity(rename_selector = "crossChainTransferToSubstrate")]
+	pub fn cross_chain_transfer_assets_to_substrate(
+		&mut self,
+		caller: Caller,
+		chain_id: ChainId,
+		receiver: H256,
+		assets: Vec<(Address, U256)>,
+		fee_asset: Address,
+		weight_limit: u64,
+	) -> Result<()> {
+		self.transfer_to(
+			caller,
+			chain_id,
+			Beneficiary::AccountId32(receiver),
+			assets,
+			fee_asset,
+			transfer::weight_limit(weight_limit),
+		)
+	}
+
+	/// Returns the status of the cross-chain transfer by the id of its message:
+	/// 0 - unknown, 1 - pending, 2 - delivered, 3 - failed, 4 - trapped.
+	fn cross_chain_transfer_status(&self, message_id: H256) -> Result<u8> {
+		self.consume_store_reads(1)?;
+		Ok(transfer::status_code::<T>(message_id))
+	}
+
+	/// Returns true if cross-chain transfers of the asset are paused, either by an operator
+	/// of the asset or by the governance of the bridge flows.
+	fn bridging_paused(&self) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		let asset = <Pallet<T>>::asset_id_to_address(self.asset_id());
+		Ok(<pallet_bridge_flows::Pallet<T>>::is_bridging_paused(&asset))
+	}
+
+	/// Forbid cross-chain transfers of the asset.
+	///
+	/// Only available to the asset owner or an account with the BRIDGE_OPERATOR permission.
+	fn pause_bridging(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::BRIDGE_OPERATOR,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		let asset = <Pallet<T>>::asset_id_to_address(self.asset_id());
+		<pallet_bridge_flows::Pallet<T>>::set_bridging_paused(asset, true);
+		Ok(())
+	}
+
+	/// Allow cross-chain transfers of the asset again.
+	///
+	/// Only available to the asset owner or an account with the BRIDGE_OPERATOR permission.
+	fn unpause_bridging(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::BRIDGE_OPERATOR,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		let asset = <Pallet<T>>::asset_id_to_address(self.asset_id());
+		<pallet_bridge_flows::Pallet<T>>::set_bridging_paused(asset, false);
+		Ok(())
 	}
 }
 
@@ -208,8 +427,17 @@ impl<T: Config> FungibleAssetsHandle<T> {
 	///
 	/// Permissions bits.
 	///
-	/// 1 bit: allow account to mint new tokens.
-	/// 2 - 8 bits: reserved.
+	/// 1 bit: allow account to mint new tokens, up to its minting allowance.
+	/// 2 bit: allow account to freeze and unfreeze balances.
+	/// 3 bit: allow account to pause and unpause the asset.
+	/// 4 bit: allow account to burn tokens of any holder without allowance,
+	/// must be granted explicitly, even to the owner.
+	/// 5 bit: allow account to change and freeze the asset metadata.
+	/// 6 bit: allow account to pause and unpause cross-chain transfers.
+	/// 7 - 64 bits: reserved.
+	///
+	/// Minting can not be granted with this method, use `configureMinter`,
+	/// which also sets the minting allowance. Existing minters keep minting.
 	fn set_account_permissions(
 		&mut self,
 		caller: Caller,
@@ -222,12 +450,361 @@ impl<T: Config> FungibleAssetsHandle<T> {
 		<Pallet<T>>::check_owner(self.asset_id(), &caller).map_err(dispatch_to_evm::<T>)?;
 
 		let permissions = AccountPermissions::from_bits_truncate(permissions);
-		<Pallet<T>>::set_account_permissions(self.asset_id(), &account, permissions);
+		<Pallet<T>>::set_account_permissions(self.asset_id(), &account, permissions)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Returns permissions bits of the account.
+	///
+	/// See `setAccountPermissions` for their meaning.
+	fn permissions_of(&self, account: Address) -> Result<u64> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::permissions_of(self.asset_id(), &account).bits())
+	}
+}
+
+#[solidity_interface(name = AllowanceExtensions, is(ERC20), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Atomically increases the allowance granted to `spender` by the caller.
+	fn increase_allowance(
+		&mut self,
+		caller: Caller,
+		spender: Address,
+		added_value: U256,
+	) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		let added_value = added_value.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::increase_allowance(self.asset_id(), &caller, &spender, added_value)
+			.map_err(dispatch_to_evm::<T>)?;
+		Ok(true)
+	}
+
+	/// Atomically decreases the allowance granted to `spender` by the caller.
+	fn decrease_allowance(
+		&mut self,
+		caller: Caller,
+		spender: Address,
+		subtracted_value: U256,
+	) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		let subtracted_value = subtracted_value.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::decrease_allowance(self.asset_id(), &caller, &spender, subtracted_value)
+			.map_err(dispatch_to_evm::<T>)?;
+		Ok(true)
+	}
+}
+
+/// Signed approvals, see [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612).
+#[solidity_interface(name = ERC20Permit, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+	/// given `owner`'s signed approval.
+	#[allow(clippy::too_many_arguments)]
+	fn permit(
+		&mut self,
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> Result<()> {
+		self.consume_store_reads(4)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::permit(self.asset_id(), &owner, &spender, value, deadline, v, r, s)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Returns the current nonce for `owner`, which must be included in the signed approval.
+	fn nonces(&self, owner: Address) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::nonce(self.asset_id(), &owner))
+	}
+
+	/// Returns the domain separator used in the encoding of the signed approval.
+	#[solidity(rename_selector = "DOMAIN_SEPARATOR")]
+	fn domain_separator(&self) -> Result<H256> {
+		self.consume_store_reads(2)?;
+		<Pallet<T>>::domain_separator(self.asset_id()).map_err(dispatch_to_evm::<T>)
+	}
+}
+
+/// Supply cap and minting quotas.
+#[solidity_interface(name = MintingExtensions, events(MintingEvents), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Returns the maximum total supply of the asset.
+	/// If the supply is not capped, the maximum uint128 value is returned.
+	fn max_supply(&self) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::max_supply(self.asset_id()).into())
+	}
+
+	/// Change the maximum total supply of the asset.
+	/// The maximum uint128 value removes the cap.
+	/// The total supply includes the amount transferred to other chains.
+	///
+	/// Only available to the asset owner.
+	fn set_max_supply(&mut self, caller: Caller, max_supply: U256) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_owner(self.asset_id(), &caller).map_err(dispatch_to_evm::<T>)?;
+
+		let max_supply = max_supply.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::set_max_supply(self.asset_id(), max_supply).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Returns the amount the minter is still allowed to mint.
+	/// Zero is returned for accounts, which are not minters.
+	fn minter_allowance(&self, minter: Address) -> Result<U256> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::minter_allowance(self.asset_id(), &minter).into())
+	}
+
+	/// Allow the account to mint up to `allowance` tokens.
+	/// Replaces the remaining allowance if the account is already a minter.
+	///
+	/// Only available to the asset owner.
+	fn configure_minter(&mut self, caller: Caller, minter: Address, allowance: U256) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::check_owner(self.asset_id(), &caller).map_err(dispatch_to_evm::<T>)?;
+
+		let allowance = allowance.try_into().map_err(|_| "value overflow")?;
+		<Pallet<T>>::configure_minter(self.asset_id(), &minter, allowance);
+		Ok(())
+	}
+
+	/// Revoke the minting permission and allowance of the account.
+	///
+	/// Only available to the asset owner.
+	fn remove_minter(&mut self, caller: Caller, minter: Address) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::check_owner(self.asset_id(), &caller).map_err(dispatch_to_evm::<T>)?;
 
+		<Pallet<T>>::remove_minter(self.asset_id(), &minter);
 		Ok(())
 	}
 }
 
+/// Paying EVM transaction fees in the asset instead of the native currency.
+#[solidity_interface(name = FeeExtensions, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Returns true if the account pays EVM transaction fees in the asset.
+	fn pays_fees_with(&self, account: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::fee_asset(&account).as_ref() == Some(self.asset_id()))
+	}
+
+	/// Pay EVM transaction fees of the caller in the asset, or in the native currency again.
+	///
+	/// Fees are converted from the native currency at the rate set by governance,
+	/// transactions are paid in the native currency while there is no rate for the asset.
+	fn pay_fees_with(&mut self, caller: Caller, enabled: bool) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(1)?;
+
+		if enabled {
+			return <Pallet<T>>::set_fee_asset(&caller, Some(*self.asset_id()))
+				.map_err(dispatch_to_evm::<T>);
+		}
+		if <Pallet<T>>::fee_asset(&caller).as_ref() == Some(self.asset_id()) {
+			<Pallet<T>>::set_fee_asset(&caller, None).map_err(dispatch_to_evm::<T>)?;
+		}
+		Ok(())
+	}
+}
+
+/// Compliance controls: per-account freeze and asset-wide pause.
+#[solidity_interface(name = ComplianceExtensions, events(ComplianceEvents), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Returns true if the balance of the account is frozen.
+	fn is_frozen(&self, account: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::is_frozen(self.asset_id(), &account))
+	}
+
+	/// Returns true if all movements of the asset are paused.
+	fn paused(&self) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::is_paused(self.asset_id()))
+	}
+
+	/// Forbid any movements of the account balance.
+	///
+	/// Only available to the asset owner or an account with the FREEZER permission.
+	fn freeze(&mut self, caller: Caller, account: Address) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::FREEZER,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		<Pallet<T>>::set_frozen(self.asset_id(), &account, true).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Allow movements of the account balance again.
+	///
+	/// Only available to the asset owner or an account with the FREEZER permission.
+	fn unfreeze(&mut self, caller: Caller, account: Address) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::FREEZER,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		<Pallet<T>>::set_frozen(self.asset_id(), &account, false).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Forbid all movements of the asset, including minting and burning.
+	///
+	/// Only available to the asset owner or an account with the PAUSER permission.
+	fn pause(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::PAUSER,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		<Pallet<T>>::set_paused(self.asset_id(), &caller, true).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Allow movements of the asset again.
+	///
+	/// Only available to the asset owner or an account with the PAUSER permission.
+	fn unpause(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::PAUSER,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		<Pallet<T>>::set_paused(self.asset_id(), &caller, false).map_err(dispatch_to_evm::<T>)
+	}
+}
+
+/// Two-step ownership transfer, compatible with OpenZeppelin `Ownable2Step`.
+#[solidity_interface(name = Ownable2Step, events(OwnableEvents), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Returns the address of the current owner.
+	fn owner(&self) -> Result<Address> {
+		self.consume_store_reads(1)?;
+		<Pallet<T>>::owner(self.asset_id()).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Returns the address of the pending owner.
+	fn pending_owner(&self) -> Result<Address> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::pending_owner(self.asset_id()))
+	}
+
+	/// Starts the ownership transfer of the asset to a new account.
+	/// Replaces the pending transfer if there is one.
+	///
+	/// Can only be called by the current owner.
+	fn transfer_ownership(&mut self, caller: Caller, new_owner: Address) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::start_ownership_transfer(self.asset_id(), &caller, &new_owner)
+			.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// The new owner accepts the ownership transfer.
+	fn accept_ownership(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::accept_ownership(self.asset_id(), &caller).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Leaves the asset without owner.
+	/// It will not be possible to call owner-only functions anymore.
+	///
+	/// Can only be called by the current owner.
+	fn renounce_ownership(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::renounce_ownership(self.asset_id(), &caller).map_err(dispatch_to_evm::<T>)
+	}
+}
+
+#[solidity_interface(name = MetadataExtensions, is(ERC20), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> FungibleAssetsHandle<T> {
+	/// Change metadata of the asset.
+	///
+	/// Only available to the asset owner or an account with the METADATA_ADMIN permission,
+	/// while the metadata is not frozen. The name used by `permit` is not changed.
+	fn set_metadata(
+		&mut self,
+		caller: Caller,
+		name: String,
+		symbol: String,
+		decimals: u8,
+	) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(2)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::METADATA_ADMIN,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		<Pallet<T>>::update_metadata(
+			self.asset_id(),
+			name.into_bytes(),
+			symbol.into_bytes(),
+			decimals,
+		)
+		.map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Freeze metadata of the asset, so it can no longer be changed.
+	///
+	/// Only available to the asset owner or an account with the METADATA_ADMIN permission.
+	fn freeze_metadata(&mut self, caller: Caller) -> Result<()> {
+		self.consume_store_reads(2)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::check_account_permissions(
+			self.asset_id(),
+			&caller,
+			AccountPermissions::METADATA_ADMIN,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+
+		<Pallet<T>>::freeze_metadata(self.asset_id()).map_err(dispatch_to_evm::<T>)
+	}
+}
+
 /// Implements [`OnMethodCall`], which delegates call to [`NativeFungibleHandle`]
 pub struct AdapterOnMethodCall<T: Config>(PhantomData<*const T>);
 impl<T: Config> OnMethodCall<T> for AdapterOnMethodCall<T>
@@ -263,7 +840,20 @@ where
 
 #[solidity_interface(
 	name = NativeFungibleAssets,
-	is(ERC20, ERC20Burnable, ERC20Mintable, XcmExtensions, PermissionsExtensions),
+	is(
+		ERC20,
+		ERC20Burnable,
+		ERC20Mintable,
+		XcmExtensions,
+		PermissionsExtensions,
+		MetadataExtensions,
+		Ownable2Step,
+		ComplianceExtensions,
+		MintingExtensions,
+		ERC20Permit,
+		AllowanceExtensions,
+		FeeExtensions,
+	),
 	enum(derive(PreDispatch))
 )]
 impl<T: Config> FungibleAssetsHandle<T>
@@ -0,0 +1,152 @@
+use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::Caller};
+use pallet_evm::{OnMethodCall, PrecompileHandle, PrecompileResult};
+use pallet_evm_coder_substrate::{
+	dispatch_to_evm,
+	execution::{PreDispatch, Result},
+	frontier_contract,
+};
+
+use crate::*;
+
+/// Code reported for the factory address.
+/// Calls never reach it, since they are intercepted by [`FactoryOnMethodCall`],
+/// it is only needed for the address to be seen as a contract.
+const FACTORY_STUB_CODE: [u8; 5] = hex_literal::hex!("60006000fd");
+
+/// Handle for the assets factory contract
+pub struct AssetsFactoryHandle<T: Config>(SubstrateRecorder<T>);
+
+impl<T: Config> AssetsFactoryHandle<T> {
+	/// Creates a handle
+	pub fn new_with_gas_limit(gas_limit: u64) -> AssetsFactoryHandle<T> {
+		Self(SubstrateRecorder::new(gas_limit))
+	}
+}
+
+impl<T: Config> WithRecorder<T> for AssetsFactoryHandle<T> {
+	fn recorder(&self) -> &pallet_evm_coder_substrate::SubstrateRecorder<T> {
+		&self.0
+	}
+	fn into_recorder(self) -> pallet_evm_coder_substrate::SubstrateRecorder<T> {
+		self.0
+	}
+}
+
+impl<T: Config> Deref for AssetsFactoryHandle<T> {
+	type Target = SubstrateRecorder<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+frontier_contract! {
+	macro_rules! AssetsFactoryHandle_result {...}
+	impl<T: Config> Contract for AssetsFactoryHandle<T> {...}
+}
+
+#[derive(ToLog)]
+pub enum AssetsFactoryEvents {
+	AssetCreated {
+		#[indexed]
+		asset: Address,
+		#[indexed]
+		owner: Address,
+	},
+	AssetDestroyed {
+		#[indexed]
+		asset: Address,
+	},
+}
+
+#[solidity_interface(name = EvmAssetsFactory, events(AssetsFactoryEvents), enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> AssetsFactoryHandle<T> {
+	/// Create a new asset owned by the caller.
+	///
+	/// The asset creation deposit is reserved from the caller's balance.
+	///
+	/// @param name Name of the asset.
+	/// @param symbol Ticker symbol of the asset.
+	/// @param decimals Number of decimals of the asset.
+	/// @return Address of the created asset contract.
+	fn create_asset(
+		&mut self,
+		caller: Caller,
+		name: String,
+		symbol: String,
+		decimals: u8,
+	) -> Result<Address> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(5)?;
+
+		let asset_id =
+			<Pallet<T>>::create_asset(&caller, name.into_bytes(), symbol.into_bytes(), decimals)
+				.map_err(dispatch_to_evm::<T>)?;
+		let asset = <Pallet<T>>::asset_id_to_address(&asset_id);
+
+		<PalletEvm<T>>::deposit_log(
+			AssetsFactoryEvents::AssetCreated {
+				asset,
+				owner: caller,
+			}
+			.to_log(T::FactoryAddress::get()),
+		);
+
+		Ok(asset)
+	}
+
+	/// Destroy an asset created through the factory, which has no supply.
+	///
+	/// The asset creation deposit is returned to its depositor.
+	/// Only available to the asset owner.
+	///
+	/// @param asset Address of the asset contract.
+	fn destroy_asset(&mut self, caller: Caller, asset: Address) -> Result<()> {
+		self.consume_store_reads(3)?;
+		self.consume_store_writes(7)?;
+
+		let asset_id = <Pallet<T>>::address_to_asset_id(&asset).ok_or("asset not found")?;
+		<Pallet<T>>::check_owner(&asset_id, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::destroy_asset(&asset_id).map_err(dispatch_to_evm::<T>)?;
+
+		<PalletEvm<T>>::deposit_log(
+			AssetsFactoryEvents::AssetDestroyed { asset }.to_log(T::FactoryAddress::get()),
+		);
+
+		Ok(())
+	}
+
+	/// Amount reserved from the caller on asset creation.
+	fn asset_deposit(&self) -> Result<U256> {
+		let deposit: u128 = T::AssetDeposit::get().unique_saturated_into();
+		Ok(deposit.into())
+	}
+}
+
+/// Implements [`OnMethodCall`], which delegates call to [`AssetsFactoryHandle`]
+pub struct FactoryOnMethodCall<T: Config>(PhantomData<*const T>);
+impl<T: Config> OnMethodCall<T> for FactoryOnMethodCall<T> {
+	fn is_reserved(contract: &H160) -> bool {
+		contract == &T::FactoryAddress::get()
+	}
+
+	fn is_used(contract: &H160) -> bool {
+		contract == &T::FactoryAddress::get()
+	}
+
+	fn call(handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		if handle.code_address() != T::FactoryAddress::get() {
+			return None;
+		}
+
+		let factory_handle = <AssetsFactoryHandle<T>>::new_with_gas_limit(handle.remaining_gas());
+		pallet_evm_coder_substrate::call::<_, EvmAssetsFactoryCall<_>, _, _>(handle, factory_handle)
+	}
+
+	fn get_code(contract: &H160) -> Option<Vec<u8>> {
+		Self::is_used(contract).then(|| FACTORY_STUB_CODE.to_vec())
+	}
+}
+
+generate_stubgen!(gen_factory_impl, EvmAssetsFactoryCall<()>, true);
+generate_stubgen!(gen_factory_iface, EvmAssetsFactoryCall<()>, false);
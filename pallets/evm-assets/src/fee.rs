@@ -0,0 +1,152 @@
+use fp_evm::{CheckEvmTransaction, TransactionValidationError, WithdrawReason};
+use frame_support::traits::tokens::ConversionToAssetBalance;
+use pallet_evm::{OnChargeEVMTransaction, OnCheckEvmTransaction};
+
+use crate::*;
+
+/// Fees withdrawn by [`EvmAssetsFeeAdapter`].
+pub enum FeeLiquidityInfo<N> {
+	/// Fees paid in the native currency by the wrapped adapter.
+	Native(N),
+	/// Fees paid in the asset, `paid` is already deposited to the fee collector.
+	Asset { asset: AssetId, paid: Balance },
+}
+
+impl<N: Default> Default for FeeLiquidityInfo<N> {
+	fn default() -> Self {
+		Self::Native(N::default())
+	}
+}
+
+/// Takes EVM transaction fees in the asset chosen by the caller, see [`Pallet::set_fee_asset`],
+/// falling back to the `Native` adapter otherwise.
+///
+/// Sponsored transactions are always paid by `Native`, which is expected to charge the sponsor.
+/// Native fees are converted to the asset by `Rate`, the asset is paid to the `FeeCollector`
+/// address and the overpaid part is refunded once the transaction is executed.
+/// Priority fees are not separated, the whole asset fee goes to the `FeeCollector`.
+pub struct EvmAssetsFeeAdapter<Native, Rate, FeeCollector>(
+	PhantomData<(Native, Rate, FeeCollector)>,
+);
+
+/// Converts the native fee to the asset.
+fn asset_fee<Rate>(fee: U256, asset: AssetId) -> Option<Balance>
+where
+	Rate: ConversionToAssetBalance<Balance, AssetId, Balance>,
+{
+	let fee: Balance = fee.try_into().ok()?;
+	Rate::to_asset_balance(fee, asset).ok()
+}
+
+impl<T, Native, Rate, FeeCollector> OnChargeEVMTransaction<T>
+	for EvmAssetsFeeAdapter<Native, Rate, FeeCollector>
+where
+	T: Config + pallet_evm_transaction_payment::Config,
+	Native: OnChargeEVMTransaction<T>,
+	Rate: ConversionToAssetBalance<Balance, AssetId, Balance>,
+	FeeCollector: Get<H160>,
+{
+	type LiquidityInfo = FeeLiquidityInfo<Native::LiquidityInfo>;
+
+	fn withdraw_fee(
+		who: &T::CrossAccountId,
+		reason: WithdrawReason,
+		fee: U256,
+	) -> Result<Self::LiquidityInfo, pallet_evm::Error<T>> {
+		let asset = <Pallet<T>>::fee_asset(who.as_eth())
+			.filter(|_| !fee.is_zero())
+			.filter(|_| !pallet_evm_transaction_payment::is_sponsored::<T>(who, &reason, fee));
+		let Some((asset, paid)) =
+			asset.and_then(|asset| Some((asset, asset_fee::<Rate>(fee, asset)?)))
+		else {
+			return Native::withdraw_fee(who, reason, fee).map(FeeLiquidityInfo::Native);
+		};
+
+		<Pallet<T>>::transfer(&asset, who.as_eth(), &FeeCollector::get(), paid)
+			.map_err(|_| pallet_evm::Error::<T>::BalanceLow)?;
+		Ok(FeeLiquidityInfo::Asset { asset, paid })
+	}
+
+	fn correct_and_deposit_fee(
+		who: &T::CrossAccountId,
+		corrected_fee: U256,
+		base_fee: U256,
+		already_withdrawn: Self::LiquidityInfo,
+	) -> Self::LiquidityInfo {
+		let (asset, paid) = match already_withdrawn {
+			FeeLiquidityInfo::Native(already_withdrawn) => {
+				return FeeLiquidityInfo::Native(Native::correct_and_deposit_fee(
+					who,
+					corrected_fee,
+					base_fee,
+					already_withdrawn,
+				))
+			}
+			FeeLiquidityInfo::Asset { asset, paid } => (asset, paid),
+		};
+
+		let corrected = asset_fee::<Rate>(corrected_fee, asset)
+			.unwrap_or(paid)
+			.min(paid);
+		let refund = paid - corrected;
+		if !refund.is_zero() {
+			if let Err(error) =
+				<Pallet<T>>::transfer(&asset, &FeeCollector::get(), who.as_eth(), refund)
+			{
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to refund the fee of {:?} in asset {}: {:?}",
+					who.as_eth(),
+					asset,
+					error
+				);
+			}
+		}
+
+		Default::default()
+	}
+
+	fn pay_priority_fee(tip: Self::LiquidityInfo) {
+		if let FeeLiquidityInfo::Native(tip) = tip {
+			Native::pay_priority_fee(tip)
+		}
+	}
+}
+
+/// Accepts transactions, whose fee can not be paid in the native currency,
+/// if the caller is able to pay it in the chosen asset, see [`EvmAssetsFeeAdapter`].
+pub struct FeeAssetValidity<Inner, Rate>(PhantomData<(Inner, Rate)>);
+
+impl<T, Inner, Rate> OnCheckEvmTransaction<T> for FeeAssetValidity<Inner, Rate>
+where
+	T: Config,
+	Inner: OnCheckEvmTransaction<T>,
+	Rate: ConversionToAssetBalance<Balance, AssetId, Balance>,
+{
+	fn on_check_evm_transaction(
+		v: &mut CheckEvmTransaction,
+		origin: &T::CrossAccountId,
+	) -> Result<(), TransactionValidationError> {
+		match Inner::on_check_evm_transaction(v, origin) {
+			Err(TransactionValidationError::BalanceTooLow) => {}
+			result => return result,
+		}
+
+		let asset = <Pallet<T>>::fee_asset(origin.as_eth())
+			.ok_or(TransactionValidationError::BalanceTooLow)?;
+		let max_fee_per_gas = v.transaction_fee_input()?.0;
+		let fee = max_fee_per_gas.saturating_mul(v.transaction.gas_limit);
+		let paid =
+			asset_fee::<Rate>(fee, asset).ok_or(TransactionValidationError::BalanceTooLow)?;
+		if v.who.balance < v.transaction.value
+			|| <Pallet<T>>::balance(&asset, origin.as_eth()) < paid
+			|| <Pallet<T>>::ensure_movable(&asset, origin.as_eth()).is_err()
+		{
+			return Err(TransactionValidationError::BalanceTooLow);
+		}
+
+		// Only the value is paid in the native currency
+		v.who.balance = v.transaction.value.saturating_add(fee);
+		Ok(())
+	}
+}
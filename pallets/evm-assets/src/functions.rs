@@ -1,7 +1,7 @@
 use crate::*;
 
 impl<T: Config> Pallet<T> {
-	pub(crate) fn address_to_asset_id(address: &Address) -> Option<AssetId> {
+	pub fn address_to_asset_id(address: &Address) -> Option<AssetId> {
 		let (prefix, id) = address.as_fixed_bytes().split_at(4);
 		if prefix != T::AddressPrefix::get() {
 			return None;
@@ -9,7 +9,7 @@ impl<T: Config> Pallet<T> {
 		Some(AssetId::from_be_bytes(<[u8; 16]>::try_from(id).ok()?))
 	}
 
-	pub(crate) fn asset_id_to_address(asset: &AssetId) -> H160 {
+	pub fn asset_id_to_address(asset: &AssetId) -> H160 {
 		let mut buff = [0; 20];
 		buff[..4].copy_from_slice(&T::AddressPrefix::get());
 		buff[4..20].copy_from_slice(&AssetId::to_be_bytes(*asset));
@@ -68,10 +68,29 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		ensure!(from != &Address::zero(), <Error<T>>::ERC20InvalidSender);
 		ensure!(to != &Address::zero(), <Error<T>>::ERC20InvalidReceiver);
+		Self::ensure_movable(asset, from)?;
 
 		Self::update(asset, from, to, amount)
 	}
 
+	pub fn is_frozen(asset: &AssetId, account: &Address) -> bool {
+		<FrozenAccounts<T>>::contains_key(asset, account)
+	}
+
+	pub fn is_paused(asset: &AssetId) -> bool {
+		<PausedAssets<T>>::contains_key(asset)
+	}
+
+	/// Checks that the asset is not paused and the funds of `from` are not frozen.
+	pub fn ensure_movable(asset: &AssetId, from: &Address) -> DispatchResult {
+		ensure!(!Self::is_paused(asset), <Error<T>>::AssetPaused);
+		ensure!(!Self::is_frozen(asset, from), <Error<T>>::AccountFrozen);
+		Ok(())
+	}
+
+	/// Moves, mints or burns the funds, the zero address stands for minting and burning.
+	///
+	/// Pauses and freezes are not checked.
 	pub(crate) fn update(
 		asset: &AssetId,
 		from: &Address,
@@ -84,6 +103,10 @@ impl<T: Config> Pallet<T> {
 				.supply
 				.checked_add(amount)
 				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				to == &T::CheckAccount::get() || asset_details.supply <= Self::max_supply(asset),
+				<Error<T>>::MaxSupplyExceeded
+			);
 			<Asset<T>>::set(asset, Some(asset_details));
 		} else {
 			let from_balance = Self::balance(asset, from);
@@ -111,6 +134,28 @@ impl<T: Config> Pallet<T> {
 			.to_log(Self::asset_id_to_address(asset)),
 		);
 
+		let asset_id = *asset;
+		Self::deposit_event(if from == &Address::zero() {
+			Event::Minted {
+				asset_id,
+				to: *to,
+				amount,
+			}
+		} else if to == &Address::zero() {
+			Event::Burned {
+				asset_id,
+				from: *from,
+				amount,
+			}
+		} else {
+			Event::Transferred {
+				asset_id,
+				from: *from,
+				to: *to,
+				amount,
+			}
+		});
+
 		Ok(())
 	}
 
@@ -132,6 +177,14 @@ impl<T: Config> Pallet<T> {
 		ensure!(owner != &Address::zero(), <Error<T>>::ERC20InvalidApprover);
 		ensure!(spender != &Address::zero(), <Error<T>>::Erc20InvalidSpender);
 		<Approvals<T>>::insert((asset, owner, spender), amount);
+		// Allowance changes are reported to substrate regardless of `emit_event`,
+		// since it only controls the ERC20 `Approval` log.
+		Self::deposit_event(Event::ApprovalChanged {
+			asset_id: *asset,
+			owner: *owner,
+			spender: *spender,
+			amount,
+		});
 
 		if emit_event {
 			<PalletEvm<T>>::deposit_log(
@@ -147,6 +200,30 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub fn increase_allowance(
+		asset: &AssetId,
+		owner: &Address,
+		spender: &Address,
+		added_value: Balance,
+	) -> DispatchResult {
+		let allowance = <Approvals<T>>::get((asset, owner, spender))
+			.checked_add(added_value)
+			.ok_or(ArithmeticError::Overflow)?;
+		Self::approve(asset, owner, spender, allowance, true)
+	}
+
+	pub fn decrease_allowance(
+		asset: &AssetId,
+		owner: &Address,
+		spender: &Address,
+		subtracted_value: Balance,
+	) -> DispatchResult {
+		let allowance = <Approvals<T>>::get((asset, owner, spender))
+			.checked_sub(subtracted_value)
+			.ok_or(<Error<T>>::ERC20InsufficientAllowance)?;
+		Self::approve(asset, owner, spender, allowance, true)
+	}
+
 	pub fn spend_allowance(
 		asset: &AssetId,
 		owner: &Address,
@@ -171,6 +248,7 @@ impl<T: Config> Pallet<T> {
 		to: &Address,
 		amount: Balance,
 	) -> DispatchResult {
+		Self::ensure_movable(asset, from)?;
 		Self::spend_allowance(asset, from, spender, amount)?;
 		Self::transfer(asset, from, to, amount)
 	}
@@ -186,16 +264,43 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Changes the permissions of `account`.
+	///
+	/// MINT is only kept for the existing minters, it is granted along with
+	/// the minting allowance by [`Self::configure_minter`].
 	pub fn set_account_permissions(
 		asset: &AssetId,
 		account: &Address,
 		permissions: AccountPermissions,
-	) {
+	) -> DispatchResult {
+		ensure!(
+			!permissions.contains(AccountPermissions::MINT)
+				|| Self::permissions_of(asset, account).contains(AccountPermissions::MINT),
+			<Error<T>>::MinterNotConfigured
+		);
+		Self::write_permissions(asset, account, permissions);
+		Ok(())
+	}
+
+	fn write_permissions(asset: &AssetId, account: &Address, permissions: AccountPermissions) {
 		if permissions.is_empty() {
 			<Permissions<T>>::remove(asset, account);
 		} else {
 			<Permissions<T>>::insert(asset, account, permissions);
 		}
+		if !permissions.contains(AccountPermissions::MINT) {
+			<MinterAllowances<T>>::remove(asset, account);
+		}
+
+		Self::deposit_event(Event::PermissionsChanged {
+			asset_id: *asset,
+			account: *account,
+			permissions,
+		});
+	}
+
+	pub fn permissions_of(asset: &AssetId, account: &Address) -> AccountPermissions {
+		<Permissions<T>>::get(asset, account)
 	}
 
 	pub fn check_account_permissions(
@@ -204,7 +309,8 @@ impl<T: Config> Pallet<T> {
 		permissions: AccountPermissions,
 	) -> DispatchResult {
 		let asset_details = <Asset<T>>::get(asset).ok_or(<Error<T>>::AssetNotFound)?;
-		if &asset_details.owner == account {
+		if &asset_details.owner == account && !permissions.intersects(AccountPermissions::EXPLICIT)
+		{
 			return Ok(());
 		}
 
@@ -218,12 +324,377 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	pub fn set_frozen(asset: &AssetId, account: &Address, frozen: bool) -> DispatchResult {
+		ensure!(Self::asset_exists(*asset), <Error<T>>::AssetNotFound);
+		if frozen == Self::is_frozen(asset, account) {
+			return Ok(());
+		}
+
+		let asset_id = *asset;
+		let account = *account;
+		let contract = Self::asset_id_to_address(asset);
+		if frozen {
+			<FrozenAccounts<T>>::insert(asset, account, ());
+			<PalletEvm<T>>::deposit_log(eth::ComplianceEvents::Frozen { account }.to_log(contract));
+			Self::deposit_event(Event::AccountFrozen { asset_id, account });
+		} else {
+			<FrozenAccounts<T>>::remove(asset, account);
+			<PalletEvm<T>>::deposit_log(
+				eth::ComplianceEvents::Unfrozen { account }.to_log(contract),
+			);
+			Self::deposit_event(Event::AccountThawed { asset_id, account });
+		}
+		Ok(())
+	}
+
+	/// Pauses or resumes the asset. `account` is reported as the initiator of the change.
+	pub fn set_paused(asset: &AssetId, account: &Address, paused: bool) -> DispatchResult {
+		ensure!(Self::asset_exists(*asset), <Error<T>>::AssetNotFound);
+		if paused == Self::is_paused(asset) {
+			return Ok(());
+		}
+
+		let asset_id = *asset;
+		let account = *account;
+		let contract = Self::asset_id_to_address(asset);
+		if paused {
+			<PausedAssets<T>>::insert(asset, ());
+			<PalletEvm<T>>::deposit_log(eth::ComplianceEvents::Paused { account }.to_log(contract));
+			Self::deposit_event(Event::AssetPaused { asset_id });
+		} else {
+			<PausedAssets<T>>::remove(asset);
+			<PalletEvm<T>>::deposit_log(
+				eth::ComplianceEvents::Unpaused { account }.to_log(contract),
+			);
+			Self::deposit_event(Event::AssetUnpaused { asset_id });
+		}
+		Ok(())
+	}
+
+	/// Returns the asset, in which the account pays EVM transaction fees.
+	/// Destroyed assets are ignored.
+	pub fn fee_asset(account: &Address) -> Option<AssetId> {
+		<FeeAsset<T>>::get(account).filter(|asset| Self::asset_exists(*asset))
+	}
+
+	/// Makes the account pay EVM transaction fees in the asset, or in the native currency if `None`.
+	pub fn set_fee_asset(account: &Address, asset: Option<AssetId>) -> DispatchResult {
+		if let Some(asset) = asset {
+			ensure!(Self::asset_exists(asset), <Error<T>>::AssetNotFound);
+		}
+
+		<FeeAsset<T>>::set(account, asset);
+		Self::deposit_event(Event::FeeAssetSet {
+			account: *account,
+			asset_id: asset,
+		});
+		Ok(())
+	}
+
+	/// Returns the supply cap of the asset, [`Balance::MAX`] if it is not capped.
+	pub fn max_supply(asset: &AssetId) -> Balance {
+		<MaxSupply<T>>::get(asset).unwrap_or(Balance::MAX)
+	}
+
+	/// Sets the supply cap of the asset, [`Balance::MAX`] removes it.
+	///
+	/// The cap applies to the total supply, which includes the balance of
+	/// [`Config::CheckAccount`], i.e. the amount teleported to other chains.
+	/// Minting into the check account is not limited, so teleports back are never blocked.
+	pub fn set_max_supply(asset: &AssetId, max_supply: Balance) -> DispatchResult {
+		ensure!(
+			Self::total_supply(asset) <= max_supply,
+			<Error<T>>::MaxSupplyBelowSupply
+		);
+
+		let max_supply = (max_supply != Balance::MAX).then_some(max_supply);
+		<MaxSupply<T>>::set(asset, max_supply);
+		Self::deposit_event(Event::MaxSupplySet {
+			asset_id: *asset,
+			max_supply,
+		});
+		Ok(())
+	}
+
+	/// Returns the remaining minting allowance, zero if the account is not a minter.
+	pub fn minter_allowance(asset: &AssetId, minter: &Address) -> Balance {
+		<MinterAllowances<T>>::get(asset, minter)
+	}
+
+	/// Grants the MINT permission to `minter`, limited by `allowance`.
+	pub fn configure_minter(asset: &AssetId, minter: &Address, allowance: Balance) {
+		let permissions = Self::permissions_of(asset, minter) | AccountPermissions::MINT;
+		Self::write_permissions(asset, minter, permissions);
+		<MinterAllowances<T>>::insert(asset, minter, allowance);
+
+		<PalletEvm<T>>::deposit_log(
+			eth::MintingEvents::MinterConfigured {
+				minter: *minter,
+				allowance: allowance.into(),
+			}
+			.to_log(Self::asset_id_to_address(asset)),
+		);
+		Self::deposit_event(Event::MinterConfigured {
+			asset_id: *asset,
+			minter: *minter,
+			allowance,
+		});
+	}
+
+	/// Revokes the MINT permission of `minter` along with its allowance.
+	pub fn remove_minter(asset: &AssetId, minter: &Address) {
+		let permissions = Self::permissions_of(asset, minter) - AccountPermissions::MINT;
+		// The allowance is removed along with the permission
+		Self::write_permissions(asset, minter, permissions);
+
+		<PalletEvm<T>>::deposit_log(
+			eth::MintingEvents::MinterRemoved { minter: *minter }
+				.to_log(Self::asset_id_to_address(asset)),
+		);
+		Self::deposit_event(Event::MinterRemoved {
+			asset_id: *asset,
+			minter: *minter,
+		});
+	}
+
+	/// Decreases the minting allowance of `minter`.
+	///
+	/// The asset owner is never limited.
+	pub fn spend_minter_allowance(
+		asset: &AssetId,
+		minter: &Address,
+		amount: Balance,
+	) -> DispatchResult {
+		if Self::check_owner(asset, minter).is_ok() {
+			return Ok(());
+		}
+
+		<MinterAllowances<T>>::try_mutate(asset, minter, |allowance| {
+			*allowance = allowance
+				.checked_sub(amount)
+				.ok_or(<Error<T>>::MinterAllowanceExceeded)?;
+			Ok(())
+		})
+	}
+
+	/// Creates a new asset owned by `owner`, reserving the creation deposit
+	/// from the `owner`'s substrate mirror.
+	///
+	/// Returns the id of the created asset.
+	pub fn create_asset(
+		owner: &Address,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+	) -> Result<AssetId, sp_runtime::DispatchError> {
+		ensure!(owner != &Address::zero(), <Error<T>>::InvalidOwner);
+
+		let asset = <NextAssetId<T>>::get();
+		let metadata = Self::bounded_metadata(name, symbol, decimals)?;
+
+		let depositor = T::CrossAccountId::from_eth(*owner).as_sub().clone();
+		let deposit = T::AssetDeposit::get();
+		T::DepositCurrency::reserve(&depositor, deposit)?;
+
+		<NextAssetId<T>>::set(asset.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+		<AssetDeposits<T>>::insert(asset, (depositor, deposit));
+		Self::insert_asset(asset, *owner, metadata);
+
+		Ok(asset)
+	}
+
+	/// Removes an asset created through the factory, which has no supply,
+	/// and unreserves the creation deposit.
+	///
+	/// Balances, approvals and permissions left in the asset are cleared in `on_idle`,
+	/// the ids of the factory range are never reused.
+	pub fn destroy_asset(asset: &AssetId) -> DispatchResult {
+		ensure!(
+			*asset >= FIRST_CREATED_ASSET_ID,
+			<Error<T>>::NotFactoryAsset
+		);
+		let details = Self::asset_details(asset)?;
+		ensure!(details.supply.is_zero(), <Error<T>>::AssetHasSupply);
+
+		<Asset<T>>::remove(asset);
+		<Metadata<T>>::remove(asset);
+		<PermitNames<T>>::remove(asset);
+		<MaxSupply<T>>::remove(asset);
+		<PausedAssets<T>>::remove(asset);
+		<PendingOwner<T>>::remove(asset);
+		<DestroyedAssets<T>>::insert(asset, ());
+		if let Some((depositor, deposit)) = <AssetDeposits<T>>::take(asset) {
+			T::DepositCurrency::unreserve(&depositor, deposit);
+		}
+
+		Self::deposit_event(Event::Destroyed { asset_id: *asset });
+		Ok(())
+	}
+
+	pub(crate) fn insert_asset(
+		asset_id: AssetId,
+		owner: Address,
+		metadata: AssetMetadata<BoundedVec<u8, T::StringLimit>>,
+	) {
+		<Asset<T>>::insert(
+			asset_id,
+			AssetDetails {
+				owner,
+				supply: Zero::zero(),
+			},
+		);
+		<Metadata<T>>::insert(asset_id, metadata);
+		Self::pin_permit_name(&asset_id);
+		Self::deposit_event(Event::Created { asset_id, owner });
+	}
+
+	pub(crate) fn set_metadata(
+		asset: &AssetId,
+		metadata: AssetMetadata<BoundedVec<u8, T::StringLimit>>,
+	) {
+		Self::pin_permit_name(asset);
+		Self::deposit_event(Event::MetadataSet {
+			asset_id: *asset,
+			name: metadata.name.to_vec(),
+			symbol: metadata.symbol.to_vec(),
+			decimals: metadata.decimals,
+			is_frozen: metadata.is_frozen,
+		});
+		<Metadata<T>>::insert(asset, metadata);
+	}
+
+	/// Replaces the metadata of an asset, unless it is frozen.
+	pub fn update_metadata(
+		asset: &AssetId,
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+	) -> DispatchResult {
+		let current = <Metadata<T>>::get(asset).ok_or(<Error<T>>::AssetNotFound)?;
+		ensure!(!current.is_frozen, <Error<T>>::MetadataFrozen);
+
+		let metadata = Self::bounded_metadata(name, symbol, decimals)?;
+		Self::set_metadata(asset, metadata);
+		Ok(())
+	}
+
+	/// Forbids any further metadata changes, except by the `ForceOrigin`.
+	pub fn freeze_metadata(asset: &AssetId) -> DispatchResult {
+		let mut metadata = <Metadata<T>>::get(asset).ok_or(<Error<T>>::AssetNotFound)?;
+		ensure!(!metadata.is_frozen, <Error<T>>::MetadataFrozen);
+
+		metadata.is_frozen = true;
+		Self::set_metadata(asset, metadata);
+		Ok(())
+	}
+
+	pub fn set_owner(asset: &AssetId, new_owner: &Address) -> DispatchResult {
+		let previous_owner = <Asset<T>>::try_mutate(asset, |details| {
+			let details = details.as_mut().ok_or(<Error<T>>::AssetNotFound)?;
+			Ok::<_, sp_runtime::DispatchError>(core::mem::replace(&mut details.owner, *new_owner))
+		})?;
+
+		<PendingOwner<T>>::remove(asset);
+
+		<PalletEvm<T>>::deposit_log(
+			eth::OwnableEvents::OwnershipTransferred {
+				previous_owner,
+				new_owner: *new_owner,
+			}
+			.to_log(Self::asset_id_to_address(asset)),
+		);
+		Self::deposit_event(Event::OwnershipTransferred {
+			asset_id: *asset,
+			previous_owner,
+			new_owner: *new_owner,
+		});
+		Ok(())
+	}
+
+	pub fn owner(asset: &AssetId) -> Result<Address, sp_runtime::DispatchError> {
+		Self::asset_details(asset).map(|a| a.owner)
+	}
+
+	pub fn pending_owner(asset: &AssetId) -> Address {
+		<PendingOwner<T>>::get(asset).unwrap_or_default()
+	}
+
+	/// Starts the ownership transfer, which should be completed by `new_owner`
+	/// with [`Self::accept_ownership`].
+	///
+	/// Zero `new_owner` cancels the pending transfer.
+	pub fn start_ownership_transfer(
+		asset: &AssetId,
+		owner: &Address,
+		new_owner: &Address,
+	) -> DispatchResult {
+		Self::check_owner(asset, owner)?;
+
+		if new_owner == &Address::zero() {
+			<PendingOwner<T>>::remove(asset);
+		} else {
+			<PendingOwner<T>>::insert(asset, new_owner);
+		}
+
+		<PalletEvm<T>>::deposit_log(
+			eth::OwnableEvents::OwnershipTransferStarted {
+				previous_owner: *owner,
+				new_owner: *new_owner,
+			}
+			.to_log(Self::asset_id_to_address(asset)),
+		);
+		Ok(())
+	}
+
+	pub fn accept_ownership(asset: &AssetId, new_owner: &Address) -> DispatchResult {
+		ensure!(
+			<PendingOwner<T>>::get(asset).as_ref() == Some(new_owner),
+			<Error<T>>::OwnableUnauthorizedAccount
+		);
+		Self::set_owner(asset, new_owner)
+	}
+
+	/// Leaves the asset without owner, making owner-only functions unavailable.
+	pub fn renounce_ownership(asset: &AssetId, owner: &Address) -> DispatchResult {
+		Self::check_owner(asset, owner)?;
+		Self::set_owner(asset, &Address::zero())
+	}
+
+	pub(crate) fn bounded_metadata(
+		name: Vec<u8>,
+		symbol: Vec<u8>,
+		decimals: u8,
+	) -> Result<AssetMetadata<BoundedVec<u8, T::StringLimit>>, sp_runtime::DispatchError> {
+		Ok(AssetMetadata {
+			name: name.try_into().map_err(|_| <Error<T>>::BadMetadata)?,
+			symbol: symbol.try_into().map_err(|_| <Error<T>>::BadMetadata)?,
+			decimals,
+			is_frozen: false,
+		})
+	}
+
 	pub fn mint(asset: &AssetId, to: &Address, amount: Balance) -> DispatchResult {
+		ensure!(!Self::is_paused(asset), <Error<T>>::AssetPaused);
+		Self::mint_unchecked(asset, to, amount)
+	}
+
+	/// Mints regardless of the asset being paused.
+	pub(crate) fn mint_unchecked(asset: &AssetId, to: &Address, amount: Balance) -> DispatchResult {
 		Self::check_receiver(to)?;
 		Self::update(asset, &Address::zero(), to, amount)
 	}
 
 	pub fn burn(asset: &AssetId, account: &Address, value: Balance) -> DispatchResult {
+		Self::ensure_movable(asset, account)?;
+		Self::burn_unchecked(asset, account, value)
+	}
+
+	/// Burns regardless of the asset being paused or the account being frozen.
+	pub(crate) fn burn_unchecked(
+		asset: &AssetId,
+		account: &Address,
+		value: Balance,
+	) -> DispatchResult {
 		ensure!(account != &Address::zero(), <Error<T>>::ERC20InvalidSender);
 		Self::update(asset, account, &Address::zero(), value)
 	}
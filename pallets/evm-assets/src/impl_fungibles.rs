@@ -29,6 +29,9 @@ impl<T: Config> fungibles::Inspect<Address> for Pallet<T> {
 		_preservation: frame_support::traits::tokens::Preservation,
 		_force: frame_support::traits::tokens::Fortitude,
 	) -> Self::Balance {
+		if Self::ensure_movable(&asset, who).is_err() {
+			return Zero::zero();
+		}
 		Self::balance(&asset, who)
 	}
 
@@ -38,6 +41,16 @@ impl<T: Config> fungibles::Inspect<Address> for Pallet<T> {
 		amount: Self::Balance,
 		_provenance: frame_support::traits::tokens::Provenance,
 	) -> frame_support::traits::tokens::DepositConsequence {
+		if Self::is_paused(&asset) {
+			return DepositConsequence::Blocked;
+		}
+		let within_cap = who == &T::CheckAccount::get()
+			|| Self::total_supply(&asset)
+				.checked_add(amount)
+				.map_or(false, |supply| supply <= Self::max_supply(&asset));
+		if !within_cap {
+			return DepositConsequence::Overflow;
+		}
 		Self::balance(&asset, who)
 			.checked_add(amount)
 			.map(|_| DepositConsequence::Success)
@@ -49,6 +62,9 @@ impl<T: Config> fungibles::Inspect<Address> for Pallet<T> {
 		who: &Address,
 		amount: Self::Balance,
 	) -> frame_support::traits::tokens::WithdrawConsequence<Self::Balance> {
+		if Self::ensure_movable(&asset, who).is_err() {
+			return WithdrawConsequence::Frozen;
+		}
 		Self::balance(&asset, who)
 			.checked_sub(amount)
 			.map(|_| WithdrawConsequence::Success)
@@ -9,51 +9,70 @@ use frame_support::{
 	traits::{
 		fungibles::Unbalanced,
 		tokens::{fungibles, DepositConsequence, Precision, Preservation, WithdrawConsequence},
-		OnRuntimeUpgrade,
+		Currency, OnRuntimeUpgrade, ReservableCurrency,
 	},
 };
 pub use pallet::*;
+use pallet_chain_registry::Destination;
 use pallet_ethereum::Origin as EthereumOrigin;
 use pallet_evm::{account::CrossAccountId, Pallet as PalletEvm};
 use pallet_evm_coder_substrate::{types::String, SubstrateRecorder, WithRecorder};
-use pallet_xcm::{Pallet as PalletXcm, WeightInfo as PalletXcmWeightInfo};
-use sp_core::{Get, H160, U256};
+use pallet_xcm::WeightInfo as PalletXcmWeightInfo;
+use pallet_xcm_transfers::transfer::TransferableAssets;
+use sp_core::{Get, H160, H256, U256};
 use sp_runtime::{
-	traits::{TryConvert, Zero},
+	traits::{TryConvert, UniqueSaturatedInto, Zero},
 	ArithmeticError,
 };
-use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, ops::Deref, prelude::*};
-use staging_xcm::{
-	latest::{
-		AssetId as XcmAssetId, Fungibility, Junction, Junctions, MultiAsset as XcmAsset,
-		MultiLocation as Location, NetworkId,
-	},
-	prelude::WeightLimit,
+use sp_std::{marker::PhantomData, ops::Deref, prelude::*};
+use staging_xcm::latest::{
+	AssetId as XcmAssetId, Fungibility, Junction, Junctions, MultiLocation as Location, NetworkId,
 };
 pub mod types;
 use types::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+pub use weights::WeightInfo;
+
 pub mod functions;
 
 pub mod eth;
 
+pub mod factory;
+
+pub mod fee;
+
 pub mod hanlde;
 use hanlde::*;
 
 mod impl_fungibles;
 pub mod migration;
+pub mod permit;
+pub mod runtime_api;
 pub mod xcm;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 pub(crate) const LOG_TARGET: &str = "runtime::evm-assets";
+
+pub(crate) type DepositBalanceOf<T> =
+	<<T as Config>::DepositCurrency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::Blake2_128Concat;
+	use frame_system::pallet_prelude::*;
 	use migration::try_generate_genesis_from_sudo;
 
 	use self::migration::init_assets_with;
 	use super::*;
 
 	/// The in-code storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::error]
 	pub enum Error<T> {
@@ -66,6 +85,107 @@ pub mod pallet {
 		OwnableUnauthorizedAccount,
 		UnauthorizedAccount,
 		AssetNotFound,
+		AssetAlreadyExists,
+		InvalidOwner,
+		BadMetadata,
+		MetadataFrozen,
+		AccountFrozen,
+		AssetPaused,
+		MaxSupplyExceeded,
+		MaxSupplyBelowSupply,
+		MinterAllowanceExceeded,
+		ERC2612ExpiredSignature,
+		ERC2612InvalidSigner,
+		/// The asset was not created through the factory.
+		NotFactoryAsset,
+		/// The asset still has supply.
+		AssetHasSupply,
+		/// The id belongs to the range of the assets created through the factory.
+		ReservedAssetId,
+		/// MINT is granted only along with a minting allowance.
+		MinterNotConfigured,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An asset was created.
+		Created { asset_id: AssetId, owner: Address },
+		/// Metadata of an asset was set.
+		MetadataSet {
+			asset_id: AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			is_frozen: bool,
+		},
+		/// Ownership of an asset was transferred.
+		OwnershipTransferred {
+			asset_id: AssetId,
+			previous_owner: Address,
+			new_owner: Address,
+		},
+		/// Permissions of an account were changed.
+		PermissionsChanged {
+			asset_id: AssetId,
+			account: Address,
+			permissions: AccountPermissions,
+		},
+		/// Tokens were minted.
+		Minted {
+			asset_id: AssetId,
+			to: Address,
+			amount: Balance,
+		},
+		/// Tokens were burned.
+		Burned {
+			asset_id: AssetId,
+			from: Address,
+			amount: Balance,
+		},
+		/// Tokens were transferred.
+		Transferred {
+			asset_id: AssetId,
+			from: Address,
+			to: Address,
+			amount: Balance,
+		},
+		/// Allowance of `spender` over the `owner`'s tokens was changed.
+		ApprovalChanged {
+			asset_id: AssetId,
+			owner: Address,
+			spender: Address,
+			amount: Balance,
+		},
+		/// Balance of an account was frozen.
+		AccountFrozen { asset_id: AssetId, account: Address },
+		/// Balance of an account was unfrozen.
+		AccountThawed { asset_id: AssetId, account: Address },
+		/// All movements of an asset were paused.
+		AssetPaused { asset_id: AssetId },
+		/// Movements of an asset were resumed.
+		AssetUnpaused { asset_id: AssetId },
+		/// Supply cap of an asset was changed. `None` means there is no cap.
+		MaxSupplySet {
+			asset_id: AssetId,
+			max_supply: Option<Balance>,
+		},
+		/// Minting allowance of a minter was set.
+		MinterConfigured {
+			asset_id: AssetId,
+			minter: Address,
+			allowance: Balance,
+		},
+		/// Minter was removed.
+		MinterRemoved { asset_id: AssetId, minter: Address },
+		/// Asset, in which the account pays EVM transaction fees, was changed.
+		/// `None` means the fees are paid in the native currency.
+		FeeAssetSet {
+			account: Address,
+			asset_id: Option<AssetId>,
+		},
+		/// An asset was destroyed and the creation deposit was returned.
+		Destroyed { asset_id: AssetId },
 	}
 
 	#[pallet::config]
@@ -74,7 +194,15 @@ pub mod pallet {
 		+ pallet_evm_coder_substrate::Config
 		+ pallet_xcm::Config
 		+ pallet_ethereum::Config
+		+ pallet_xcm_transfers::Config
 	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may forcibly create assets and manage them
+		/// regardless of their owners.
+		type ForceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
 		/// Address prefix for assets evm mirrors
 		#[pallet::constant]
 		type AddressPrefix: Get<[u8; 4]>;
@@ -83,11 +211,29 @@ pub mod pallet {
 		#[pallet::constant]
 		type StringLimit: Get<u32>;
 
-		/// The type must contain only correct
-		/// and supported 'Location', since it is used "as is"
+		/// Resolves the destination of `crossChainTransfer` and the way the asset is moved there
+		/// by the chain id and the address of the transferred asset, failing if the transfer is not allowed.
+		/// The type must return only correct and supported 'Location', since it is used "as is"
 		/// and its use does not imply deep checks
+		type ChainLocator: TryConvert<(ChainId, H160), Destination>;
+
+		/// Address, under which the assets factory contract will be available
+		#[pallet::constant]
+		type FactoryAddress: Get<H160>;
+
+		/// The currency in which asset creation deposits are reserved.
+		type DepositCurrency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount of funds that must be reserved for an asset created through the factory.
 		#[pallet::constant]
-		type ChainLocator: Get<BTreeMap<ChainId, Location>>;
+		type AssetDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// The account, which holds the teleported amounts of the assets.
+		/// Its balance is the supply on other chains, so minting into it is not limited by the supply cap.
+		type CheckAccount: Get<Address>;
 	}
 
 	#[pallet::storage]
@@ -139,10 +285,254 @@ pub mod pallet {
 	pub(super) type SupportedAssets<T: Config> =
 		StorageValue<Value = Assets, QueryKind = ValueQuery>;
 
+	#[pallet::storage]
+	/// The maximum total supply of an asset. Assets without an entry are not capped.
+	pub(super) type MaxSupply<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Balance>;
+
+	#[pallet::storage]
+	/// Remaining amount a minter is allowed to mint.
+	/// Minters without an entry are not allowed to mint.
+	pub(super) type MinterAllowances<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetId,
+		Blake2_128Concat,
+		Address,
+		Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Names of the assets in the domain of the signed approvals, see [`Pallet::domain_separator`].
+	/// The name is kept when the asset is created, or before its first rename.
+	pub(super) type PermitNames<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, BoundedVec<u8, T::StringLimit>>;
+
+	#[pallet::storage]
+	/// Nonces of the signed approvals, see [`Pallet::permit`].
+	pub(super) type Nonces<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, Address, U256, ValueQuery>;
+
+	#[pallet::storage]
+	/// Accounts, whose balances can not be moved.
+	pub(super) type FrozenAccounts<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, Address, ()>;
+
+	#[pallet::storage]
+	/// Assets, whose movements are paused.
+	pub(super) type PausedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, ()>;
+
+	#[pallet::storage]
+	/// Asset, in which an account pays EVM transaction fees, see [`fee::EvmAssetsFeeAdapter`].
+	/// Accounts without an entry pay in the native currency.
+	pub(super) type FeeAsset<T: Config> = StorageMap<_, Blake2_128Concat, Address, AssetId>;
+
+	#[pallet::storage]
+	/// Account which is allowed to accept the ownership of an asset.
+	pub(super) type PendingOwner<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, Address>;
+
+	#[pallet::type_value]
+	pub(super) fn DefaultNextAssetId() -> AssetId {
+		FIRST_CREATED_ASSET_ID
+	}
+
+	#[pallet::storage]
+	/// The id which will be given to the next asset created through the factory.
+	pub(super) type NextAssetId<T: Config> =
+		StorageValue<_, AssetId, ValueQuery, DefaultNextAssetId>;
+
+	#[pallet::storage]
+	/// Depositor and amount reserved for an asset created through the factory.
+	pub(super) type AssetDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetId, (T::AccountId, DepositBalanceOf<T>)>;
+
+	#[pallet::storage]
+	/// Destroyed assets, whose balances, approvals, permissions and nonces are yet to be cleared.
+	pub(super) type DestroyedAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, ()>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Clears the accounts storage of a destroyed asset, as much as fits in the block.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// Looking up the asset and forgetting it once it is cleared
+			let base = db_weight.reads_writes(1, 1);
+			let limit = remaining_weight.saturating_sub(base).ref_time()
+				/ db_weight.writes(1).ref_time().max(1);
+			if remaining_weight.any_lt(base) || limit == 0 {
+				return Weight::zero();
+			}
+
+			let Some(asset) = <DestroyedAssets<T>>::iter_keys().next() else {
+				return db_weight.reads(1);
+			};
+			let clear_prefixes: [fn(AssetId, u32) -> sp_io::MultiRemovalResults; 6] = [
+				|asset, limit| <Balances<T>>::clear_prefix(asset, limit, None),
+				|asset, limit| <Approvals<T>>::clear_prefix((asset,), limit, None),
+				|asset, limit| <Permissions<T>>::clear_prefix(asset, limit, None),
+				|asset, limit| <MinterAllowances<T>>::clear_prefix(asset, limit, None),
+				|asset, limit| <FrozenAccounts<T>>::clear_prefix(asset, limit, None),
+				|asset, limit| <Nonces<T>>::clear_prefix(asset, limit, None),
+			];
+
+			let mut limit = limit.min(u32::MAX as u64) as u32;
+			let mut weight = base;
+			for clear_prefix in clear_prefixes {
+				if limit == 0 {
+					return weight;
+				}
+				let removed = clear_prefix(asset, limit);
+				weight = weight.saturating_add(
+					db_weight.reads_writes(removed.loops as u64, removed.unique as u64),
+				);
+				if removed.maybe_cursor.is_some() {
+					return weight;
+				}
+				limit = limit.saturating_sub(removed.loops);
+			}
+			<DestroyedAssets<T>>::remove(asset);
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new asset with the given id, owner and metadata.
+		///
+		/// Origin must be `ForceOrigin`. Unlike the factory, no deposit is reserved.
+		/// The ids starting from the first one given by the factory are reserved for it.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::force_create())]
+		pub fn force_create(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			owner: Address,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(owner != Address::zero(), <Error<T>>::InvalidOwner);
+			ensure!(
+				asset_id < FIRST_CREATED_ASSET_ID,
+				<Error<T>>::ReservedAssetId
+			);
+			ensure!(
+				!Self::asset_exists(asset_id),
+				<Error<T>>::AssetAlreadyExists
+			);
+
+			let metadata = Self::bounded_metadata(name, symbol, decimals)?;
+			Self::insert_asset(asset_id, owner, metadata);
+			Ok(())
+		}
+
+		/// Set the metadata of an asset, ignoring whether it is frozen.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::force_set_metadata())]
+		pub fn force_set_metadata(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			name: Vec<u8>,
+			symbol: Vec<u8>,
+			decimals: u8,
+			is_frozen: bool,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Self::asset_exists(asset_id), <Error<T>>::AssetNotFound);
+
+			let mut metadata = Self::bounded_metadata(name, symbol, decimals)?;
+			metadata.is_frozen = is_frozen;
+			Self::set_metadata(&asset_id, metadata);
+			Ok(())
+		}
+
+		/// Transfer the ownership of an asset to `new_owner`.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::force_transfer_ownership())]
+		pub fn force_transfer_ownership(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			new_owner: Address,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(new_owner != Address::zero(), <Error<T>>::InvalidOwner);
+
+			Self::set_owner(&asset_id, &new_owner)
+		}
+
+		/// Change `account` permissions for an asset.
+		/// MINT is only kept for the existing minters, since it requires a minting allowance.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::force_set_permissions())]
+		pub fn force_set_permissions(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			account: Address,
+			permissions: u64,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+			ensure!(Self::asset_exists(asset_id), <Error<T>>::AssetNotFound);
+
+			let permissions = AccountPermissions::from_bits_truncate(permissions);
+			Self::set_account_permissions(&asset_id, &account, permissions)
+		}
+
+		/// Mint `amount` of an asset to `to`, even if the asset is paused.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::force_mint())]
+		pub fn force_mint(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			to: Address,
+			amount: Balance,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Self::mint_unchecked(&asset_id, &to, amount)
+		}
+
+		/// Burn `amount` of an asset from `from`, even if the asset is paused or `from` is frozen.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::force_burn())]
+		pub fn force_burn(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			from: Address,
+			amount: Balance,
+		) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Self::burn_unchecked(&asset_id, &from, amount)
+		}
+
+		/// Destroy an asset created through the factory, which has no supply,
+		/// returning the creation deposit to its depositor.
+		///
+		/// Origin must be `ForceOrigin`.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::force_destroy())]
+		pub fn force_destroy(origin: OriginFor<T>, asset_id: AssetId) -> DispatchResult {
+			T::ForceOrigin::ensure_origin(origin)?;
+
+			Self::destroy_asset(&asset_id)
+		}
+	}
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
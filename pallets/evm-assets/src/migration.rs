@@ -1,3 +1,4 @@
+use frame_support::traits::{GetStorageVersion, StorageVersion};
 use sp_io::storage::get;
 
 use crate::*;
@@ -120,6 +121,32 @@ where
 	}
 }
 
+/// Minters without an allowance used to be unlimited, now they are not allowed to mint.
+/// Keeps the minters, which were granted the permission before, unlimited.
+pub struct UnlimitedExistingMinters<T: Config>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for UnlimitedExistingMinters<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if <Pallet<T>>::on_chain_storage_version() != 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut reads = 1;
+		let mut writes = 1;
+		for (asset, account, permissions) in <Permissions<T>>::iter() {
+			reads += 2;
+			if permissions.contains(AccountPermissions::MINT)
+				&& !<MinterAllowances<T>>::contains_key(asset, account)
+			{
+				<MinterAllowances<T>>::insert(asset, account, Balance::MAX);
+				writes += 1;
+			}
+		}
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
+
 pub(crate) fn try_generate_genesis_from_sudo<T: Config>() -> GenesisConfig<T>
 where
 	T::AccountId: for<'a> TryFrom<&'a [u8]>,
@@ -0,0 +1,290 @@
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64, Everything, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use pallet_ethereum::PostLogContent;
+use pallet_evm::{
+	account::{BasicCrossAccountId, CrossAccountId as _},
+	EnsureAddressNever, HashedAddressMapping, SubstrateBlockHashMapping,
+};
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+use staging_xcm::latest::{InteriorMultiLocation, Junction::GlobalConsensus, Junctions::X1};
+use xcm_builder::{
+	AccountId32Aliases, AllowUnpaidExecutionFrom, EnsureXcmOrigin, FixedWeightBounds,
+	SignedToAccountId32,
+};
+use xcm_executor::XcmExecutor;
+
+use crate::{self as pallet_evm_assets, *};
+
+pub type AccountId = AccountId32;
+pub type CrossAccountId = BasicCrossAccountId<Test>;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		EVM: pallet_evm,
+		Ethereum: pallet_ethereum,
+		EvmCoderSubstrate: pallet_evm_coder_substrate,
+		XcmPallet: pallet_xcm,
+		ChainRegistry: pallet_chain_registry,
+		BridgeFlows: pallet_bridge_flows,
+		XcmTransfers: pallet_xcm_transfers,
+		EvmAssets: pallet_evm_assets,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u32::MAX);
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type CrossAccountId = CrossAccountId;
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BackwardsAddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BlockGasLimit = BlockGasLimit;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressNever<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type OnMethodCall = ();
+	type OnCreate = ();
+	type ChainId = ConstU64<8888>;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type FindAuthor = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type GasLimitPovSizeRatio = ConstU64<4>;
+	type OnCheckEvmTransaction = ();
+}
+
+parameter_types! {
+	pub const PostBlockAndTxnHashes: PostLogContent = PostLogContent::BlockAndTxnHashes;
+}
+
+impl pallet_ethereum::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type StateRoot = pallet_ethereum::IntermediateStateRoot<Self>;
+	type PostLogContent = PostBlockAndTxnHashes;
+	type ExtraDataLength = ConstU32<32>;
+}
+
+impl pallet_evm_coder_substrate::Config for Test {}
+
+parameter_types! {
+	pub const ThisNetwork: NetworkId = NetworkId::Polkadot;
+	pub UniversalLocation: InteriorMultiLocation = X1(GlobalConsensus(ThisNetwork::get()));
+	pub const UnitWeightCost: Weight = Weight::from_parts(10, 10);
+	pub const MaxInstructions: u32 = 100;
+}
+
+type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, ThisNetwork>;
+
+pub struct XcmConfig;
+
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = ();
+	type AssetTransactor = ();
+	type OriginConverter = pallet_xcm::XcmPassthrough<RuntimeOrigin>;
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = AllowUnpaidExecutionFrom<Everything>;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type Trader = ();
+	type ResponseHandler = XcmPallet;
+	type AssetTrap = XcmPallet;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type AssetClaims = XcmPallet;
+	type SubscriptionService = XcmPallet;
+	type PalletInstancesInfo = AllPalletsWithSystem;
+	type MaxAssetsIntoHolding = ConstU32<64>;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+}
+
+impl pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = ();
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Nothing;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Nothing;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type TrustedLockers = ();
+	type SovereignAccountOf = AccountId32Aliases<ThisNetwork, AccountId>;
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+}
+
+impl pallet_chain_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+}
+
+impl pallet_bridge_flows::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type ChainIds = ChainRegistry;
+}
+
+impl pallet_xcm_transfers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type TransferableAssets = EvmAssets;
+	type TransferResponseTimeout = ConstU64<10>;
+}
+
+pub const HOLDER: AccountId = AccountId32::new([0x22; 32]);
+pub const OWNER: Address = H160([0x11; 20]);
+pub const OTHER: Address = H160([0x33; 20]);
+pub const CHECK_ACCOUNT: Address = H160([0x44; 20]);
+pub const ASSET: AssetId = 1;
+pub const INITIAL_BALANCE: Balance = 1_000;
+pub const ASSET_DEPOSIT: Balance = 100;
+
+parameter_types! {
+	pub const Prefix: [u8; 4] = [0xFF; 4];
+	pub const FactoryAddress: H160 = H160([0xBA; 20]);
+	pub const CheckAccount: Address = CHECK_ACCOUNT;
+}
+
+impl pallet_evm_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AddressPrefix = Prefix;
+	type StringLimit = ConstU32<32>;
+	type ChainLocator = ChainRegistry;
+	type FactoryAddress = FactoryAddress;
+	type DepositCurrency = Balances;
+	type AssetDeposit = ConstU128<ASSET_DEPOSIT>;
+	type WeightInfo = ();
+	type CheckAccount = CheckAccount;
+}
+
+/// Ethereum address of [`HOLDER`], which receives the genesis assets.
+pub fn holder() -> Address {
+	*CrossAccountId::from_sub(HOLDER).as_eth()
+}
+
+/// Substrate account, from which the creation deposit of the `owner`'s assets is reserved.
+pub fn depositor(owner: Address) -> AccountId {
+	CrossAccountId::from_eth(owner).as_sub().clone()
+}
+
+/// Balance reserved from the [`depositor`] of the `owner`'s assets.
+pub fn reserved(owner: Address) -> Balance {
+	Balances::reserved_balance(depositor(owner))
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig {
+		evm_assets: pallet_evm_assets::GenesisConfig {
+			accounts: vec![HOLDER],
+			owner: None,
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&depositor(OWNER), INITIAL_BALANCE);
+		assert_ok!(EvmAssets::force_create(
+			RuntimeOrigin::root(),
+			ASSET,
+			OWNER,
+			b"Test".to_vec(),
+			b"TST".to_vec(),
+			6,
+		));
+	});
+	ext
+}
@@ -0,0 +1,77 @@
+//! [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612) signed approvals.
+
+use pallet_evm_coder_substrate::permit::{self, Permit, PermitError};
+
+use crate::*;
+
+impl<T: Config> Pallet<T> {
+	pub fn nonce(asset: &AssetId, owner: &Address) -> U256 {
+		<Nonces<T>>::get(asset, owner)
+	}
+
+	/// Returns the EIP-712 domain separator of the asset.
+	///
+	/// The name in the domain is the one the asset was created with, see [`PermitNames`],
+	/// so renaming the asset doesn't invalidate the approvals already signed.
+	pub fn domain_separator(asset: &AssetId) -> Result<H256, sp_runtime::DispatchError> {
+		let name = match <PermitNames<T>>::get(asset) {
+			Some(name) => name,
+			None => {
+				<Metadata<T>>::get(asset)
+					.ok_or(<Error<T>>::AssetNotFound)?
+					.name
+			}
+		};
+		Ok(permit::domain_separator::<T>(
+			&name,
+			&Self::asset_id_to_address(asset),
+		))
+	}
+
+	/// Keeps the current name of the asset in the domain of the signed approvals,
+	/// unless it is already kept.
+	pub(crate) fn pin_permit_name(asset: &AssetId) {
+		if <PermitNames<T>>::contains_key(asset) {
+			return;
+		}
+		if let Some(metadata) = <Metadata<T>>::get(asset) {
+			<PermitNames<T>>::insert(asset, metadata.name);
+		}
+	}
+
+	/// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+	/// given `owner`'s signed approval.
+	///
+	/// The approval is verified against the signed `value`, which is only clamped
+	/// to the maximum balance when it is set as the allowance.
+	#[allow(clippy::too_many_arguments)]
+	pub fn permit(
+		asset: &AssetId,
+		owner: &Address,
+		spender: &Address,
+		value: U256,
+		deadline: U256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> DispatchResult {
+		let nonce = Self::nonce(asset, owner);
+		let permit = Permit {
+			owner: *owner,
+			spender: *spender,
+			value,
+			nonce,
+			deadline,
+		};
+		permit
+			.verify::<T>(&Self::domain_separator(asset)?, v, r, s)
+			.map_err(|error| match error {
+				PermitError::Expired => <Error<T>>::ERC2612ExpiredSignature,
+				PermitError::InvalidSigner => <Error<T>>::ERC2612InvalidSigner,
+			})?;
+
+		<Nonces<T>>::insert(asset, owner, nonce.saturating_add(U256::one()));
+		let value = value.try_into().unwrap_or(Balance::MAX);
+		Self::approve(asset, owner, spender, value, true)
+	}
+}
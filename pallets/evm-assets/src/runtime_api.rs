@@ -0,0 +1,79 @@
+//! Runtime API for wallets and indexers, exposing the assets state without
+//! a separate `eth_call` per asset and method.
+
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::H160;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+use crate::*;
+
+/// Summary of an asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AssetInfo {
+	pub id: u128,
+	/// Address of the asset ERC20 contract.
+	pub address: H160,
+	pub name: Vec<u8>,
+	pub symbol: Vec<u8>,
+	pub decimals: u8,
+	pub supply: u128,
+	pub owner: H160,
+}
+
+sp_api::decl_runtime_apis! {
+	pub trait EvmAssetsApi {
+		/// Returns all existing assets.
+		fn assets() -> Vec<AssetInfo>;
+
+		/// Returns non-zero balances of the account as `(asset, balance)`.
+		fn balances_of(account: H160) -> Vec<(u128, u128)>;
+
+		/// Returns non-zero allowances given by the owner as `(asset, spender, allowance)`.
+		fn allowances_of(owner: H160) -> Vec<(u128, H160, u128)>;
+
+		/// Returns permissions bits of the account for the asset.
+		fn permissions_of(asset: u128, account: H160) -> u64;
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn api_assets() -> Vec<AssetInfo> {
+		<Asset<T>>::iter()
+			.map(|(id, details)| {
+				let metadata = <Metadata<T>>::get(id).unwrap_or_default();
+				AssetInfo {
+					id,
+					address: Self::asset_id_to_address(&id),
+					name: metadata.name.into_inner(),
+					symbol: metadata.symbol.into_inner(),
+					decimals: metadata.decimals,
+					supply: details.supply,
+					owner: details.owner,
+				}
+			})
+			.collect()
+	}
+
+	pub fn api_balances_of(account: &Address) -> Vec<(AssetId, Balance)> {
+		<Asset<T>>::iter_keys()
+			.map(|asset| (asset, <Balances<T>>::get(asset, account)))
+			.filter(|(_, balance)| !balance.is_zero())
+			.collect()
+	}
+
+	pub fn api_allowances_of(owner: &Address) -> Vec<(AssetId, Address, Balance)> {
+		<Asset<T>>::iter_keys()
+			.flat_map(|asset| {
+				<Approvals<T>>::iter_prefix((asset, owner))
+					.filter(|(_, allowance)| !allowance.is_zero())
+					.map(move |(spender, allowance)| (asset, spender, allowance))
+			})
+			.collect()
+	}
+
+	pub fn api_permissions_of(asset: &AssetId, account: &Address) -> u64 {
+		<Permissions<T>>::get(asset, account).bits()
+	}
+}
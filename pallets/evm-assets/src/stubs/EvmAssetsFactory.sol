@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: OTHER
+// This code is automatically generated
+
+pragma solidity >=0.8.0 <0.9.0;
+
+/// @dev common stubs holder
+contract Dummy {
+	uint8 dummy;
+	string stub_error = "this contract is implemented in native";
+}
+
+contract ERC165 is Dummy {
+	function supportsInterface(bytes4 interfaceID) external view returns (bool) {
+		require(false, stub_error);
+		interfaceID;
+		return true;
+	}
+}
+
+/// @dev inlined interface
+contract AssetsFactoryEvents {
+	event AssetCreated(address indexed asset, address indexed owner);
+	event AssetDestroyed(address indexed asset);
+}
+
+/// @dev the ERC-165 identifier for this interface is 0xabdbf91a
+contract EvmAssetsFactory is Dummy, ERC165, AssetsFactoryEvents {
+	/// Create a new asset owned by the caller.
+	///
+	/// The asset creation deposit is reserved from the caller's balance.
+	///
+	/// @param name Name of the asset.
+	/// @param symbol Ticker symbol of the asset.
+	/// @param decimals Number of decimals of the asset.
+	/// @return Address of the created asset contract.
+	/// @dev EVM selector for this function is: 0xda7b7ce3,
+	///  or in textual repr: createAsset(string,string,uint8)
+	function createAsset(
+		string memory name,
+		string memory symbol,
+		uint8 decimals
+	) public returns (address) {
+		require(false, stub_error);
+		name;
+		symbol;
+		decimals;
+		dummy = 0;
+		return 0x0000000000000000000000000000000000000000;
+	}
+
+	/// Destroy an asset created through the factory, which has no supply.
+	///
+	/// The asset creation deposit is returned to its depositor.
+	/// Only available to the asset owner.
+	///
+	/// @param asset Address of the asset contract.
+	/// @dev EVM selector for this function is: 0x2e80cbd4,
+	///  or in textual repr: destroyAsset(address)
+	function destroyAsset(address asset) public {
+		require(false, stub_error);
+		asset;
+		dummy = 0;
+	}
+
+	/// Amount reserved from the caller on asset creation.
+	/// @dev EVM selector for this function is: 0x5f204e2d,
+	///  or in textual repr: assetDeposit()
+	function assetDeposit() public view returns (uint256) {
+		require(false, stub_error);
+		dummy;
+		return 0;
+	}
+}
@@ -17,6 +17,32 @@ contract ERC165 is Dummy {
 	}
 }
 
+/// Paying EVM transaction fees in the asset instead of the native currency.
+/// @dev the ERC-165 identifier for this interface is 0x7d391a58
+contract FeeExtensions is Dummy, ERC165 {
+	/// Returns true if the account pays EVM transaction fees in the asset.
+	/// @dev EVM selector for this function is: 0xd31e04e1,
+	///  or in textual repr: paysFeesWith(address)
+	function paysFeesWith(address account) public view returns (bool) {
+		require(false, stub_error);
+		account;
+		dummy;
+		return false;
+	}
+
+	/// Pay EVM transaction fees of the caller in the asset, or in the native currency again.
+	///
+	/// Fees are converted from the native currency at the rate set by governance,
+	/// transactions are paid in the native currency while there is no rate for the asset.
+	/// @dev EVM selector for this function is: 0xae271eb9,
+	///  or in textual repr: payFeesWith(bool)
+	function payFeesWith(bool enabled) public {
+		require(false, stub_error);
+		enabled;
+		dummy = 0;
+	}
+}
+
 /// @dev inlined interface
 contract ERC20Events {
 	event Transfer(address indexed from, address indexed to, uint256 value);
@@ -112,8 +138,368 @@ contract ERC20 is Dummy, ERC165, ERC20Events {
 	}
 }
 
-/// @dev the ERC-165 identifier for this interface is 0xee18d38e
-contract XcmExtensions is Dummy, ERC165, ERC20 {
+/// @dev the ERC-165 identifier for this interface is 0x9d075186
+contract AllowanceExtensions is Dummy, ERC165, ERC20 {
+	/// Atomically increases the allowance granted to `spender` by the caller.
+	/// @dev EVM selector for this function is: 0x39509351,
+	///  or in textual repr: increaseAllowance(address,uint256)
+	function increaseAllowance(address spender, uint256 addedValue) public returns (bool) {
+		require(false, stub_error);
+		spender;
+		addedValue;
+		dummy = 0;
+		return false;
+	}
+
+	/// Atomically decreases the allowance granted to `spender` by the caller.
+	/// @dev EVM selector for this function is: 0xa457c2d7,
+	///  or in textual repr: decreaseAllowance(address,uint256)
+	function decreaseAllowance(address spender, uint256 subtractedValue) public returns (bool) {
+		require(false, stub_error);
+		spender;
+		subtractedValue;
+		dummy = 0;
+		return false;
+	}
+}
+
+/// Signed approvals, see [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612).
+/// @dev the ERC-165 identifier for this interface is 0x9d8ff7da
+contract ERC20Permit is Dummy, ERC165 {
+	/// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+	/// given `owner`'s signed approval.
+	/// @dev EVM selector for this function is: 0xd505accf,
+	///  or in textual repr: permit(address,address,uint256,uint256,uint8,bytes32,bytes32)
+	function permit(
+		address owner,
+		address spender,
+		uint256 value,
+		uint256 deadline,
+		uint8 v,
+		bytes32 r,
+		bytes32 s
+	) public {
+		require(false, stub_error);
+		owner;
+		spender;
+		value;
+		deadline;
+		v;
+		r;
+		s;
+		dummy = 0;
+	}
+
+	/// Returns the current nonce for `owner`, which must be included in the signed approval.
+	/// @dev EVM selector for this function is: 0x7ecebe00,
+	///  or in textual repr: nonces(address)
+	function nonces(address owner) public view returns (uint256) {
+		require(false, stub_error);
+		owner;
+		dummy;
+		return 0;
+	}
+
+	/// Returns the domain separator used in the encoding of the signed approval.
+	/// @dev EVM selector for this function is: 0x3644e515,
+	///  or in textual repr: DOMAIN_SEPARATOR()
+	function DOMAIN_SEPARATOR() public view returns (bytes32) {
+		require(false, stub_error);
+		dummy;
+		return bytes32(0);
+	}
+}
+
+/// @dev inlined interface
+contract MintingEvents {
+	event MinterConfigured(address indexed minter, uint256 allowance);
+	event MinterRemoved(address indexed minter);
+}
+
+/// Supply cap and minting quotas.
+/// @dev the ERC-165 identifier for this interface is 0x4e9b60f1
+contract MintingExtensions is Dummy, ERC165, MintingEvents {
+	/// Returns the maximum total supply of the asset.
+	/// If the supply is not capped, the maximum uint128 value is returned.
+	/// @dev EVM selector for this function is: 0xd5abeb01,
+	///  or in textual repr: maxSupply()
+	function maxSupply() public view returns (uint256) {
+		require(false, stub_error);
+		dummy;
+		return 0;
+	}
+
+	/// Change the maximum total supply of the asset.
+	/// The maximum uint128 value removes the cap.
+	/// The total supply includes the amount transferred to other chains.
+	///
+	/// Only available to the asset owner.
+	/// @dev EVM selector for this function is: 0x6f8b44b0,
+	///  or in textual repr: setMaxSupply(uint256)
+	function setMaxSupply(uint256 maxSupply) public {
+		require(false, stub_error);
+		maxSupply;
+		dummy = 0;
+	}
+
+	/// Returns the amount the minter is still allowed to mint.
+	/// Zero is returned for accounts, which are not minters.
+	/// @dev EVM selector for this function is: 0x8a6db9c3,
+	///  or in textual repr: minterAllowance(address)
+	function minterAllowance(address minter) public view returns (uint256) {
+		require(false, stub_error);
+		minter;
+		dummy;
+		return 0;
+	}
+
+	/// Allow the account to mint up to `allowance` tokens.
+	/// Replaces the remaining allowance if the account is already a minter.
+	///
+	/// Only available to the asset owner.
+	/// @dev EVM selector for this function is: 0x4e44d956,
+	///  or in textual repr: configureMinter(address,uint256)
+	function configureMinter(address minter, uint256 allowance) public {
+		require(false, stub_error);
+		minter;
+		allowance;
+		dummy = 0;
+	}
+
+	/// Revoke the minting permission and allowance of the account.
+	///
+	/// Only available to the asset owner.
+	/// @dev EVM selector for this function is: 0x3092afd5,
+	///  or in textual repr: removeMinter(address)
+	function removeMinter(address minter) public {
+		require(false, stub_error);
+		minter;
+		dummy = 0;
+	}
+}
+
+/// @dev inlined interface
+contract ComplianceEvents {
+	event Paused(address account);
+	event Unpaused(address account);
+	event Frozen(address indexed account);
+	event Unfrozen(address indexed account);
+}
+
+/// Compliance controls: per-account freeze and asset-wide pause.
+/// @dev the ERC-165 identifier for this interface is 0xcadecf67
+contract ComplianceExtensions is Dummy, ERC165, ComplianceEvents {
+	/// Returns true if the balance of the account is frozen.
+	/// @dev EVM selector for this function is: 0xe5839836,
+	///  or in textual repr: isFrozen(address)
+	function isFrozen(address account) public view returns (bool) {
+		require(false, stub_error);
+		account;
+		dummy;
+		return false;
+	}
+
+	/// Returns true if all movements of the asset are paused.
+	/// @dev EVM selector for this function is: 0x5c975abb,
+	///  or in textual repr: paused()
+	function paused() public view returns (bool) {
+		require(false, stub_error);
+		dummy;
+		return false;
+	}
+
+	/// Forbid any movements of the account balance.
+	///
+	/// Only available to the asset owner or an account with the FREEZER permission.
+	/// @dev EVM selector for this function is: 0x8d1fdf2f,
+	///  or in textual repr: freeze(address)
+	function freeze(address account) public {
+		require(false, stub_error);
+		account;
+		dummy = 0;
+	}
+
+	/// Allow movements of the account balance again.
+	///
+	/// Only available to the asset owner or an account with the FREEZER permission.
+	/// @dev EVM selector for this function is: 0x45c8b1a6,
+	///  or in textual repr: unfreeze(address)
+	function unfreeze(address account) public {
+		require(false, stub_error);
+		account;
+		dummy = 0;
+	}
+
+	/// Forbid all movements of the asset, including minting and burning.
+	///
+	/// Only available to the asset owner or an account with the PAUSER permission.
+	/// @dev EVM selector for this function is: 0x8456cb59,
+	///  or in textual repr: pause()
+	function pause() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+
+	/// Allow movements of the asset again.
+	///
+	/// Only available to the asset owner or an account with the PAUSER permission.
+	/// @dev EVM selector for this function is: 0x3f4ba83a,
+	///  or in textual repr: unpause()
+	function unpause() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+}
+
+/// @dev inlined interface
+contract OwnableEvents {
+	event OwnershipTransferStarted(address indexed previousOwner, address indexed newOwner);
+	event OwnershipTransferred(address indexed previousOwner, address indexed newOwner);
+}
+
+/// Two-step ownership transfer, compatible with OpenZeppelin `Ownable2Step`.
+/// @dev the ERC-165 identifier for this interface is 0x94be5999
+contract Ownable2Step is Dummy, ERC165, OwnableEvents {
+	/// Returns the address of the current owner.
+	/// @dev EVM selector for this function is: 0x8da5cb5b,
+	///  or in textual repr: owner()
+	function owner() public view returns (address) {
+		require(false, stub_error);
+		dummy;
+		return 0x0000000000000000000000000000000000000000;
+	}
+
+	/// Returns the address of the pending owner.
+	/// @dev EVM selector for this function is: 0xe30c3978,
+	///  or in textual repr: pendingOwner()
+	function pendingOwner() public view returns (address) {
+		require(false, stub_error);
+		dummy;
+		return 0x0000000000000000000000000000000000000000;
+	}
+
+	/// Starts the ownership transfer of the asset to a new account.
+	/// Replaces the pending transfer if there is one.
+	///
+	/// Can only be called by the current owner.
+	/// @dev EVM selector for this function is: 0xf2fde38b,
+	///  or in textual repr: transferOwnership(address)
+	function transferOwnership(address newOwner) public {
+		require(false, stub_error);
+		newOwner;
+		dummy = 0;
+	}
+
+	/// The new owner accepts the ownership transfer.
+	/// @dev EVM selector for this function is: 0x79ba5097,
+	///  or in textual repr: acceptOwnership()
+	function acceptOwnership() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+
+	/// Leaves the asset without owner.
+	/// It will not be possible to call owner-only functions anymore.
+	///
+	/// Can only be called by the current owner.
+	/// @dev EVM selector for this function is: 0x715018a6,
+	///  or in textual repr: renounceOwnership()
+	function renounceOwnership() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0xe6c393a9
+contract MetadataExtensions is Dummy, ERC165, ERC20 {
+	/// Change metadata of the asset.
+	///
+	/// Only available to the asset owner or an account with the METADATA_ADMIN permission,
+	/// while the metadata is not frozen. The name used by `permit` is not changed.
+	/// @dev EVM selector for this function is: 0x37d2c2f4,
+	///  or in textual repr: setMetadata(string,string,uint8)
+	function setMetadata(
+		string memory name,
+		string memory symbol,
+		uint8 decimals
+	) public {
+		require(false, stub_error);
+		name;
+		symbol;
+		decimals;
+		dummy = 0;
+	}
+
+	/// Freeze metadata of the asset, so it can no longer be changed.
+	///
+	/// Only available to the asset owner or an account with the METADATA_ADMIN permission.
+	/// @dev EVM selector for this function is: 0xd111515d,
+	///  or in textual repr: freezeMetadata()
+	function freezeMetadata() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0xd3a4d5ae
+contract PermissionsExtensions is Dummy, ERC165, ERC20 {
+	/// Change account permissions.
+	///
+	/// Permissions bits.
+	///
+	/// 1 bit: allow account to mint new tokens, up to its minting allowance.
+	/// 2 bit: allow account to freeze and unfreeze balances.
+	/// 3 bit: allow account to pause and unpause the asset.
+	/// 4 bit: allow account to burn tokens of any holder without allowance,
+	/// must be granted explicitly, even to the owner.
+	/// 5 bit: allow account to change and freeze the asset metadata.
+	/// 6 bit: allow account to pause and unpause cross-chain transfers.
+	/// 7 - 64 bits: reserved.
+	///
+	/// Minting can not be granted with this method, use `configureMinter`,
+	/// which also sets the minting allowance. Existing minters keep minting.
+	/// @dev EVM selector for this function is: 0xd901570d,
+	///  or in textual repr: setAccountPermissions(address,uint64)
+	function setAccountPermissions(address account, uint64 permissions) public {
+		require(false, stub_error);
+		account;
+		permissions;
+		dummy = 0;
+	}
+
+	/// Returns permissions bits of the account.
+	///
+	/// See `setAccountPermissions` for their meaning.
+	/// @dev EVM selector for this function is: 0x0aa582a3,
+	///  or in textual repr: permissionsOf(address)
+	function permissionsOf(address account) public view returns (uint64) {
+		require(false, stub_error);
+		account;
+		dummy;
+		return 0;
+	}
+}
+
+/// @dev anonymous struct
+struct Tuple0 {
+	address field_0;
+	uint256 field_1;
+}
+
+/// @dev inlined interface
+contract XcmEvents {
+	event CrossChainTransferInitiated(uint64 chainId, bytes32 receiver, uint256 amount, bytes32 indexed messageId);
+	event CrossChainAssetTransferInitiated(
+		address indexed asset,
+		uint64 chainId,
+		bytes32 receiver,
+		uint256 amount,
+		bytes32 indexed messageId
+	);
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x69666339
+contract XcmExtensions is Dummy, ERC165, ERC20, XcmEvents {
 	/// @dev EVM selector for this function is: 0xee18d38e,
 	///  or in textual repr: crossChainTransfer(uint64,address,uint256)
 	function crossChainTransfer(
@@ -127,6 +513,104 @@ contract XcmExtensions is Dummy, ERC165, ERC20 {
 		amount;
 		dummy = 0;
 	}
+
+	/// Transfer to a Substrate account, e.g. sr25519, on the destination chain.
+	/// @dev EVM selector for this function is: 0x6d3002d2,
+	///  or in textual repr: crossChainTransferToSubstrate(uint64,bytes32,uint256)
+	function crossChainTransferToSubstrate(
+		uint64 chainId,
+		bytes32 receiver,
+		uint256 amount
+	) public {
+		require(false, stub_error);
+		chainId;
+		receiver;
+		amount;
+		dummy = 0;
+	}
+
+	/// Transfer several assets, identified by the addresses of their contracts,
+	/// paying for the execution on the destination chain with the fee asset.
+	///
+	/// The weight limit is the maximum execution time bought on the destination,
+	/// `uint64` max means no limit.
+	/// @dev EVM selector for this function is: 0xf3f85c1c,
+	///  or in textual repr: crossChainTransfer(uint64,address,(address,uint256)[],address,uint64)
+	function crossChainTransfer(
+		uint64 chainId,
+		address receiver,
+		Tuple0[] memory assets,
+		address feeAsset,
+		uint64 weightLimit
+	) public {
+		require(false, stub_error);
+		chainId;
+		receiver;
+		assets;
+		feeAsset;
+		weightLimit;
+		dummy = 0;
+	}
+
+	/// Transfer several assets to a Substrate account, e.g. sr25519, on the destination chain.
+	/// @dev EVM selector for this function is: 0x9de8bc75,
+	///  or in textual repr: crossChainTransferToSubstrate(uint64,bytes32,(address,uint256)[],address,uint64)
+	function crossChainTransferToSubstrate(
+		uint64 chainId,
+		bytes32 receiver,
+		Tuple0[] memory assets,
+		address feeAsset,
+		uint64 weightLimit
+	) public {
+		require(false, stub_error);
+		chainId;
+		receiver;
+		assets;
+		feeAsset;
+		weightLimit;
+		dummy = 0;
+	}
+
+	/// Returns the status of the cross-chain transfer by the id of its message:
+	/// 0 - unknown, 1 - pending, 2 - delivered, 3 - failed, 4 - trapped.
+	/// @dev EVM selector for this function is: 0x32ed2a46,
+	///  or in textual repr: crossChainTransferStatus(bytes32)
+	function crossChainTransferStatus(bytes32 messageId) public view returns (uint8) {
+		require(false, stub_error);
+		messageId;
+		dummy;
+		return 0;
+	}
+
+	/// Returns true if cross-chain transfers of the asset are paused, either by an operator
+	/// of the asset or by the chain registry governance.
+	/// @dev EVM selector for this function is: 0x52b61b52,
+	///  or in textual repr: bridgingPaused()
+	function bridgingPaused() public view returns (bool) {
+		require(false, stub_error);
+		dummy;
+		return false;
+	}
+
+	/// Forbid cross-chain transfers of the asset.
+	///
+	/// Only available to the asset owner or an account with the BRIDGE_OPERATOR permission.
+	/// @dev EVM selector for this function is: 0xae0e890a,
+	///  or in textual repr: pauseBridging()
+	function pauseBridging() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
+
+	/// Allow cross-chain transfers of the asset again.
+	///
+	/// Only available to the asset owner or an account with the BRIDGE_OPERATOR permission.
+	/// @dev EVM selector for this function is: 0x4a0bea12,
+	///  or in textual repr: unpauseBridging()
+	function unpauseBridging() public {
+		require(false, stub_error);
+		dummy = 0;
+	}
 }
 
 /// @dev the ERC-165 identifier for this interface is 0x40c10f19
@@ -161,4 +645,19 @@ contract ERC20Burnable is Dummy, ERC165, ERC20 {
 	}
 }
 
-contract NativeFungibleAssets is Dummy, ERC165, ERC20, ERC20Burnable, ERC20Mintable, XcmExtensions {}
+contract NativeFungibleAssets is
+	Dummy,
+	ERC165,
+	ERC20,
+	ERC20Burnable,
+	ERC20Mintable,
+	XcmExtensions,
+	PermissionsExtensions,
+	MetadataExtensions,
+	Ownable2Step,
+	ComplianceExtensions,
+	MintingExtensions,
+	ERC20Permit,
+	AllowanceExtensions,
+	FeeExtensions
+{}
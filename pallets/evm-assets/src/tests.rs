@@ -0,0 +1,603 @@
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use pallet_evm_coder_substrate::permit::Permit;
+use sp_core::{ecdsa, Pair};
+use sp_io::hashing::keccak_256;
+use sp_runtime::DispatchError;
+
+use crate::{mock::*, runtime_api::AssetInfo, *};
+
+fn signature(pair: &ecdsa::Pair, digest: &[u8; 32]) -> [u8; 65] {
+	let signature = pair.sign_prehashed(digest);
+	let mut bytes = [0; 65];
+	bytes.copy_from_slice(signature.as_ref());
+	bytes
+}
+
+fn eth_address(pair: &ecdsa::Pair) -> Address {
+	let digest = [0; 32];
+	let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature(pair, &digest), &digest)
+		.expect("signature is valid");
+	H160::from_slice(&keccak_256(&public)[12..])
+}
+
+fn permit(pair: &ecdsa::Pair, spender: Address, value: U256, deadline: U256) -> DispatchResult {
+	let owner = eth_address(pair);
+	let digest = Permit {
+		owner,
+		spender,
+		value,
+		nonce: EvmAssets::nonce(&ASSET, &owner),
+		deadline,
+	}
+	.digest(&EvmAssets::domain_separator(&ASSET)?);
+	let signature = signature(pair, &digest);
+
+	EvmAssets::permit(
+		&ASSET,
+		&owner,
+		&spender,
+		value,
+		deadline,
+		signature[64] + 27,
+		H256::from_slice(&signature[..32]),
+		H256::from_slice(&signature[32..64]),
+	)
+}
+
+#[test]
+fn genesis_assets_are_given_to_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(EvmAssets::balance(&RED_ID, &holder()), BALANCE * NATIVE);
+		assert_eq!(EvmAssets::balance(&GBP_ID, &holder()), BALANCE * CURRENCY);
+		assert_eq!(EvmAssets::owner(&RED_ID), Ok(holder()));
+		assert_eq!(EvmAssets::decimals(&GBP_ID), Ok(6));
+	});
+}
+
+#[test]
+fn force_create_checks_id_owner_and_metadata() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EvmAssets::force_create(
+				RuntimeOrigin::signed(HOLDER),
+				2,
+				OWNER,
+				b"Test".to_vec(),
+				b"TST".to_vec(),
+				6
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			EvmAssets::force_create(
+				RuntimeOrigin::root(),
+				FIRST_CREATED_ASSET_ID,
+				OWNER,
+				b"Test".to_vec(),
+				b"TST".to_vec(),
+				6
+			),
+			<Error<Test>>::ReservedAssetId
+		);
+		assert_noop!(
+			EvmAssets::force_create(
+				RuntimeOrigin::root(),
+				ASSET,
+				OWNER,
+				b"Test".to_vec(),
+				b"TST".to_vec(),
+				6
+			),
+			<Error<Test>>::AssetAlreadyExists
+		);
+		assert_noop!(
+			EvmAssets::force_create(
+				RuntimeOrigin::root(),
+				2,
+				Address::zero(),
+				b"Test".to_vec(),
+				b"TST".to_vec(),
+				6
+			),
+			<Error<Test>>::InvalidOwner
+		);
+		assert_noop!(
+			EvmAssets::force_create(
+				RuntimeOrigin::root(),
+				2,
+				OWNER,
+				vec![b'A'; 33],
+				b"TST".to_vec(),
+				6
+			),
+			<Error<Test>>::BadMetadata
+		);
+
+		assert_ok!(EvmAssets::force_create(
+			RuntimeOrigin::root(),
+			2,
+			OWNER,
+			b"Test".to_vec(),
+			b"TST".to_vec(),
+			6
+		));
+		System::assert_last_event(
+			Event::Created {
+				asset_id: 2,
+				owner: OWNER,
+			}
+			.into(),
+		);
+		// No deposit is reserved for the forcibly created assets
+		assert_eq!(reserved(OWNER), 0);
+	});
+}
+
+#[test]
+fn transfer_from_spends_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmAssets::force_mint(
+			RuntimeOrigin::root(),
+			ASSET,
+			holder(),
+			100
+		));
+		assert_ok!(EvmAssets::approve(&ASSET, &holder(), &OTHER, 60, true));
+		System::assert_last_event(
+			Event::ApprovalChanged {
+				asset_id: ASSET,
+				owner: holder(),
+				spender: OTHER,
+				amount: 60,
+			}
+			.into(),
+		);
+
+		assert_ok!(EvmAssets::transfer_from(
+			&ASSET,
+			&OTHER,
+			&holder(),
+			&OTHER,
+			50
+		));
+		assert_eq!(EvmAssets::allowance(&ASSET, &holder(), &OTHER), 10.into());
+		assert_eq!(EvmAssets::balance(&ASSET, &OTHER), 50);
+		assert_noop!(
+			EvmAssets::transfer_from(&ASSET, &OTHER, &holder(), &OTHER, 20),
+			<Error<Test>>::ERC20InsufficientAllowance
+		);
+		assert_noop!(
+			EvmAssets::transfer(&ASSET, &holder(), &Address::zero(), 1),
+			<Error<Test>>::ERC20InvalidReceiver
+		);
+
+		// Infinite allowance is not spent
+		assert_ok!(EvmAssets::approve(
+			&ASSET,
+			&holder(),
+			&OTHER,
+			Balance::MAX,
+			true
+		));
+		assert_ok!(EvmAssets::transfer_from(
+			&ASSET,
+			&OTHER,
+			&holder(),
+			&OTHER,
+			50
+		));
+		assert_eq!(
+			EvmAssets::allowance(&ASSET, &holder(), &OTHER),
+			Balance::MAX.into()
+		);
+		assert_eq!(EvmAssets::total_supply(&ASSET), 100);
+	});
+}
+
+#[test]
+fn owner_has_implicit_permissions_except_explicit_ones() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmAssets::check_account_permissions(
+			&ASSET,
+			&OWNER,
+			AccountPermissions::MINT | AccountPermissions::PAUSER
+		));
+		assert_noop!(
+			EvmAssets::check_account_permissions(&ASSET, &OWNER, AccountPermissions::BURN_ANY),
+			<Error<Test>>::UnauthorizedAccount
+		);
+		assert_noop!(
+			EvmAssets::check_account_permissions(&ASSET, &OTHER, AccountPermissions::FREEZER),
+			<Error<Test>>::UnauthorizedAccount
+		);
+
+		assert_ok!(EvmAssets::force_set_permissions(
+			RuntimeOrigin::root(),
+			ASSET,
+			OTHER,
+			(AccountPermissions::FREEZER | AccountPermissions::BURN_ANY).bits()
+		));
+		assert_ok!(EvmAssets::check_account_permissions(
+			&ASSET,
+			&OTHER,
+			AccountPermissions::BURN_ANY
+		));
+		assert_noop!(
+			EvmAssets::check_account_permissions(
+				&ASSET,
+				&OTHER,
+				AccountPermissions::FREEZER | AccountPermissions::MINT
+			),
+			<Error<Test>>::UnauthorizedAccount
+		);
+		assert_eq!(
+			EvmAssets::api_permissions_of(&ASSET, &OTHER),
+			(AccountPermissions::FREEZER | AccountPermissions::BURN_ANY).bits()
+		);
+	});
+}
+
+#[test]
+fn frozen_accounts_and_paused_assets_can_not_move_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmAssets::mint(&ASSET, &holder(), 100));
+
+		assert_ok!(EvmAssets::set_frozen(&ASSET, &holder(), true));
+		System::assert_last_event(
+			Event::AccountFrozen {
+				asset_id: ASSET,
+				account: holder(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			EvmAssets::transfer(&ASSET, &holder(), &OTHER, 1),
+			<Error<Test>>::AccountFrozen
+		);
+		assert_noop!(
+			EvmAssets::burn(&ASSET, &holder(), 1),
+			<Error<Test>>::AccountFrozen
+		);
+		// Frozen accounts still receive funds
+		assert_ok!(EvmAssets::mint(&ASSET, &holder(), 1));
+
+		assert_ok!(EvmAssets::set_frozen(&ASSET, &holder(), false));
+		assert_ok!(EvmAssets::transfer(&ASSET, &holder(), &OTHER, 1));
+
+		assert_ok!(EvmAssets::set_paused(&ASSET, &OWNER, true));
+		System::assert_last_event(Event::AssetPaused { asset_id: ASSET }.into());
+		assert_noop!(
+			EvmAssets::transfer(&ASSET, &holder(), &OTHER, 1),
+			<Error<Test>>::AssetPaused
+		);
+		assert_noop!(
+			EvmAssets::mint(&ASSET, &holder(), 1),
+			<Error<Test>>::AssetPaused
+		);
+
+		assert_ok!(EvmAssets::set_paused(&ASSET, &OWNER, false));
+		assert_ok!(EvmAssets::transfer(&ASSET, &holder(), &OTHER, 1));
+	});
+}
+
+#[test]
+fn force_origin_ignores_freezes_and_pauses() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmAssets::mint(&ASSET, &holder(), 100));
+		assert_ok!(EvmAssets::set_frozen(&ASSET, &holder(), true));
+		assert_ok!(EvmAssets::set_paused(&ASSET, &OWNER, true));
+
+		assert_ok!(EvmAssets::force_burn(
+			RuntimeOrigin::root(),
+			ASSET,
+			holder(),
+			60
+		));
+		assert_ok!(EvmAssets::force_mint(
+			RuntimeOrigin::root(),
+			ASSET,
+			OTHER,
+			10
+		));
+		assert_eq!(EvmAssets::balance(&ASSET, &holder()), 40);
+		assert_eq!(EvmAssets::balance(&ASSET, &OTHER), 10);
+		assert_eq!(EvmAssets::total_supply(&ASSET), 50);
+	});
+}
+
+#[test]
+fn minting_is_limited_by_max_supply_and_minter_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmAssets::set_max_supply(&ASSET, 100));
+		assert_ok!(EvmAssets::mint(&ASSET, &holder(), 100));
+		assert_noop!(
+			EvmAssets::mint(&ASSET, &holder(), 1),
+			<Error<Test>>::MaxSupplyExceeded
+		);
+		// Teleports back are not limited
+		assert_ok!(EvmAssets::mint(&ASSET, &CHECK_ACCOUNT, 10));
+		assert_noop!(
+			EvmAssets::set_max_supply(&ASSET, 100),
+			<Error<Test>>::MaxSupplyBelowSupply
+		);
+
+		EvmAssets::configure_minter(&ASSET, &OTHER, 10);
+		assert_ok!(EvmAssets::check_account_permissions(
+			&ASSET,
+			&OTHER,
+			AccountPermissions::MINT
+		));
+		assert_ok!(EvmAssets::spend_minter_allowance(&ASSET, &OTHER, 6));
+		assert_eq!(EvmAssets::minter_allowance(&ASSET, &OTHER), 4);
+		assert_noop!(
+			EvmAssets::spend_minter_allowance(&ASSET, &OTHER, 5),
+			<Error<Test>>::MinterAllowanceExceeded
+		);
+		// The owner is never limited
+		assert_ok!(EvmAssets::spend_minter_allowance(
+			&ASSET,
+			&OWNER,
+			Balance::MAX
+		));
+
+		EvmAssets::remove_minter(&ASSET, &OTHER);
+		assert_eq!(EvmAssets::minter_allowance(&ASSET, &OTHER), 0);
+		assert_noop!(
+			EvmAssets::check_account_permissions(&ASSET, &OTHER, AccountPermissions::MINT),
+			<Error<Test>>::UnauthorizedAccount
+		);
+	});
+}
+
+#[test]
+fn mint_is_granted_only_with_allowance() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EvmAssets::force_set_permissions(
+				RuntimeOrigin::root(),
+				ASSET,
+				OTHER,
+				AccountPermissions::MINT.bits()
+			),
+			<Error<Test>>::MinterNotConfigured
+		);
+		assert_noop!(
+			EvmAssets::set_account_permissions(&ASSET, &OTHER, AccountPermissions::MINT),
+			<Error<Test>>::MinterNotConfigured
+		);
+
+		EvmAssets::configure_minter(&ASSET, &OTHER, 10);
+		// Minters keep minting when their other permissions change
+		assert_ok!(EvmAssets::force_set_permissions(
+			RuntimeOrigin::root(),
+			ASSET,
+			OTHER,
+			(AccountPermissions::MINT | AccountPermissions::FREEZER).bits()
+		));
+		assert_eq!(EvmAssets::minter_allowance(&ASSET, &OTHER), 10);
+		assert_ok!(EvmAssets::spend_minter_allowance(&ASSET, &OTHER, 10));
+	});
+}
+
+#[test]
+fn frozen_metadata_is_changed_only_by_force_origin() {
+	new_test_ext().execute_with(|| {
+		let domain_separator = EvmAssets::domain_separator(&ASSET).unwrap();
+
+		assert_ok!(EvmAssets::update_metadata(
+			&ASSET,
+			b"Renamed".to_vec(),
+			b"RNM".to_vec(),
+			8
+		));
+		assert_eq!(EvmAssets::name_of(&ASSET).unwrap(), "Renamed");
+		assert_eq!(EvmAssets::decimals(&ASSET), Ok(8));
+		// Renaming doesn't invalidate the signed approvals
+		assert_eq!(EvmAssets::domain_separator(&ASSET), Ok(domain_separator));
+
+		assert_ok!(EvmAssets::freeze_metadata(&ASSET));
+		assert_noop!(
+			EvmAssets::update_metadata(&ASSET, b"Test".to_vec(), b"TST".to_vec(), 6),
+			<Error<Test>>::MetadataFrozen
+		);
+		assert_noop!(
+			EvmAssets::freeze_metadata(&ASSET),
+			<Error<Test>>::MetadataFrozen
+		);
+
+		assert_ok!(EvmAssets::force_set_metadata(
+			RuntimeOrigin::root(),
+			ASSET,
+			b"Test".to_vec(),
+			b"TST".to_vec(),
+			6,
+			false
+		));
+		assert_ok!(EvmAssets::update_metadata(
+			&ASSET,
+			b"Test".to_vec(),
+			b"TST".to_vec(),
+			6
+		));
+	});
+}
+
+#[test]
+fn ownership_is_transferred_in_two_steps() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EvmAssets::start_ownership_transfer(&ASSET, &OTHER, &OTHER),
+			<Error<Test>>::OwnableUnauthorizedAccount
+		);
+		assert_ok!(EvmAssets::start_ownership_transfer(&ASSET, &OWNER, &OTHER));
+		assert_eq!(EvmAssets::pending_owner(&ASSET), OTHER);
+		assert_eq!(EvmAssets::owner(&ASSET), Ok(OWNER));
+
+		assert_noop!(
+			EvmAssets::accept_ownership(&ASSET, &holder()),
+			<Error<Test>>::OwnableUnauthorizedAccount
+		);
+		assert_ok!(EvmAssets::accept_ownership(&ASSET, &OTHER));
+		System::assert_last_event(
+			Event::OwnershipTransferred {
+				asset_id: ASSET,
+				previous_owner: OWNER,
+				new_owner: OTHER,
+			}
+			.into(),
+		);
+		assert_eq!(EvmAssets::pending_owner(&ASSET), Address::zero());
+
+		assert_ok!(EvmAssets::renounce_ownership(&ASSET, &OTHER));
+		assert_eq!(EvmAssets::owner(&ASSET), Ok(Address::zero()));
+		assert_noop!(
+			EvmAssets::start_ownership_transfer(&ASSET, &OTHER, &OWNER),
+			<Error<Test>>::OwnableUnauthorizedAccount
+		);
+	});
+}
+
+#[test]
+fn factory_assets_reserve_deposit_until_destroyed() {
+	new_test_ext().execute_with(|| {
+		let asset = EvmAssets::create_asset(&OWNER, b"Made".to_vec(), b"MD".to_vec(), 18).unwrap();
+		assert_eq!(asset, FIRST_CREATED_ASSET_ID);
+		assert_eq!(reserved(OWNER), ASSET_DEPOSIT);
+		assert_eq!(EvmAssets::owner(&asset), Ok(OWNER));
+
+		assert_noop!(
+			EvmAssets::force_destroy(RuntimeOrigin::root(), ASSET),
+			<Error<Test>>::NotFactoryAsset
+		);
+		assert_ok!(EvmAssets::mint(&asset, &OTHER, 1));
+		assert_noop!(
+			EvmAssets::force_destroy(RuntimeOrigin::root(), asset),
+			<Error<Test>>::AssetHasSupply
+		);
+		assert_ok!(EvmAssets::burn(&asset, &OTHER, 1));
+
+		assert_ok!(EvmAssets::set_fee_asset(&OTHER, Some(asset)));
+		assert_eq!(EvmAssets::fee_asset(&OTHER), Some(asset));
+
+		assert_ok!(EvmAssets::force_destroy(RuntimeOrigin::root(), asset));
+		System::assert_last_event(Event::Destroyed { asset_id: asset }.into());
+		assert_eq!(reserved(OWNER), 0);
+		assert!(!EvmAssets::asset_exists(asset));
+		// Destroyed assets are not used to pay fees
+		assert_eq!(EvmAssets::fee_asset(&OTHER), None);
+		assert_noop!(
+			EvmAssets::set_fee_asset(&OTHER, Some(asset)),
+			<Error<Test>>::AssetNotFound
+		);
+
+		// Ids are not reused
+		assert_eq!(
+			EvmAssets::create_asset(&OWNER, b"Made".to_vec(), b"MD".to_vec(), 18),
+			Ok(FIRST_CREATED_ASSET_ID + 1)
+		);
+	});
+}
+
+#[test]
+fn destroyed_assets_are_cleared_when_idle() {
+	new_test_ext().execute_with(|| {
+		let asset = EvmAssets::create_asset(&OWNER, b"Made".to_vec(), b"MD".to_vec(), 18).unwrap();
+		assert_ok!(EvmAssets::mint(&asset, &OTHER, 1));
+		assert_ok!(EvmAssets::burn(&asset, &OTHER, 1));
+		assert_ok!(EvmAssets::approve(&asset, &OTHER, &OWNER, 5, false));
+		assert_ok!(EvmAssets::set_frozen(&asset, &OTHER, true));
+		EvmAssets::configure_minter(&asset, &OTHER, 10);
+		assert_ok!(EvmAssets::force_destroy(RuntimeOrigin::root(), asset));
+
+		EvmAssets::on_idle(1, Weight::MAX);
+		assert_eq!(crate::Balances::<Test>::iter_prefix(asset).count(), 0);
+		assert_eq!(Approvals::<Test>::iter_prefix((asset,)).count(), 0);
+		assert_eq!(Permissions::<Test>::iter_prefix(asset).count(), 0);
+		assert_eq!(MinterAllowances::<Test>::iter_prefix(asset).count(), 0);
+		assert_eq!(FrozenAccounts::<Test>::iter_prefix(asset).count(), 0);
+		assert!(!DestroyedAssets::<Test>::contains_key(asset));
+	});
+}
+
+#[test]
+fn factory_requires_deposit() {
+	new_test_ext().execute_with(|| {
+		assert!(EvmAssets::create_asset(&OTHER, b"Made".to_vec(), b"MD".to_vec(), 18).is_err());
+		assert!(!EvmAssets::asset_exists(FIRST_CREATED_ASSET_ID));
+	});
+}
+
+#[test]
+fn permit_sets_allowance_once() {
+	new_test_ext().execute_with(|| {
+		let pair = ecdsa::Pair::from_seed(&[7; 32]);
+		let owner = eth_address(&pair);
+
+		assert_ok!(permit(&pair, OTHER, 50.into(), 1_000.into()));
+		assert_eq!(EvmAssets::allowance(&ASSET, &owner, &OTHER), 50.into());
+		assert_eq!(EvmAssets::nonce(&ASSET, &owner), 1.into());
+
+		// Signatures of other owners are rejected
+		assert_noop!(
+			EvmAssets::permit(
+				&ASSET,
+				&OWNER,
+				&OTHER,
+				50.into(),
+				1_000.into(),
+				27,
+				H256::repeat_byte(1),
+				H256::repeat_byte(1),
+			),
+			<Error<Test>>::ERC2612InvalidSigner
+		);
+
+		Timestamp::set_timestamp(1_001_000);
+		assert_noop!(
+			permit(&pair, OTHER, 50.into(), 1_000.into()),
+			<Error<Test>>::ERC2612ExpiredSignature
+		);
+	});
+}
+
+#[test]
+fn infinite_permit() {
+	new_test_ext().execute_with(|| {
+		let pair = ecdsa::Pair::from_seed(&[7; 32]);
+		let owner = eth_address(&pair);
+
+		assert_ok!(permit(&pair, OTHER, U256::MAX, U256::MAX));
+		// The signed value is verified, and only the allowance is clamped to the maximum balance
+		assert_eq!(
+			EvmAssets::allowance(&ASSET, &owner, &OTHER),
+			Balance::MAX.into()
+		);
+	});
+}
+
+#[test]
+fn runtime_api_reports_assets_balances_and_allowances() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EvmAssets::mint(&ASSET, &OTHER, 10));
+		assert_ok!(EvmAssets::approve(&ASSET, &OTHER, &OWNER, 5, false));
+
+		assert!(EvmAssets::api_assets().contains(&AssetInfo {
+			id: ASSET,
+			address: EvmAssets::asset_id_to_address(&ASSET),
+			name: b"Test".to_vec(),
+			symbol: b"TST".to_vec(),
+			decimals: 6,
+			supply: 10,
+			owner: OWNER,
+		}));
+		assert_eq!(EvmAssets::api_balances_of(&OTHER), vec![(ASSET, 10)]);
+		assert_eq!(
+			EvmAssets::api_allowances_of(&OTHER),
+			vec![(ASSET, OWNER, 5)]
+		);
+		assert_eq!(
+			EvmAssets::address_to_asset_id(&EvmAssets::asset_id_to_address(&ASSET)),
+			Some(ASSET)
+		);
+	});
+}
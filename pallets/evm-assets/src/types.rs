@@ -32,6 +32,9 @@ pub(crate) const BALANCE: Balance = 100_000_000;
 
 pub(crate) const GBP_ID: AssetId = 0xBABB0000_00000000_00000000_00000010;
 pub(crate) const RED_ID: AssetId = 0xBABB0000_00000000_00000000_00000000;
+/// Assets created through the factory are numbered starting from this id,
+/// leaving the lower range for the assets initialized by migrations.
+pub(crate) const FIRST_CREATED_ASSET_ID: AssetId = 0xBABB0000_00000000_00000001_00000000;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
 pub struct AssetDetails<Balance, Address> {
@@ -65,8 +68,26 @@ bitflags::bitflags! {
 
 bitflags::bitflags! {
 	/// Permissions of an account.
+	///
+	/// The asset owner implicitly has all of them, except [`AccountPermissions::EXPLICIT`].
 	#[derive(Encode, Decode, MaxEncodedLen, Default, TypeInfo)]
 	pub struct AccountPermissions: u64 {
+		/// Mint new tokens.
 		const MINT = 1;
+		/// Freeze and unfreeze balances of holders.
+		const FREEZER = 1 << 1;
+		/// Pause and unpause all movements of the asset.
+		const PAUSER = 1 << 2;
+		/// Burn tokens of any holder without allowance.
+		const BURN_ANY = 1 << 3;
+		/// Change and freeze the asset metadata.
+		const METADATA_ADMIN = 1 << 4;
+		/// Pause and unpause cross-chain transfers of the asset.
+		const BRIDGE_OPERATOR = 1 << 5;
 	}
 }
+
+impl AccountPermissions {
+	/// Permissions, which must be granted explicitly, even to the asset owner.
+	pub const EXPLICIT: Self = Self::BURN_ANY;
+}
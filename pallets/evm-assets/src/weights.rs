@@ -0,0 +1,117 @@
+//! Weights for `pallet_evm_assets`.
+//!
+//! [`SubstrateWeight`] accounts for the storage accesses of the extrinsics with an execution time
+//! margin, until the runtime provides the weights generated from the `benchmarking` module:
+//!
+//! `benchmark pallet --pallet=pallet_evm_assets --extrinsic=* --steps 50 --repeat 20`.
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use core::marker::PhantomData;
+
+use frame_support::{traits::Get, weights::Weight};
+
+/// Weight functions needed for `pallet_evm_assets`.
+pub trait WeightInfo {
+	fn force_create() -> Weight;
+	fn force_set_metadata() -> Weight;
+	fn force_transfer_ownership() -> Weight;
+	fn force_set_permissions() -> Weight;
+	fn force_mint() -> Weight;
+	fn force_burn() -> Weight;
+	fn force_destroy() -> Weight;
+}
+
+/// Weights for `pallet_evm_assets` using the storage accesses of the extrinsics.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `EvmAssets::Asset` (r:1 w:1)
+	/// Storage: `EvmAssets::Metadata` (r:1 w:1)
+	/// Storage: `EvmAssets::PermitNames` (r:1 w:1)
+	fn force_create() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `EvmAssets::Asset` (r:1 w:0)
+	/// Storage: `EvmAssets::Metadata` (r:1 w:1)
+	/// Storage: `EvmAssets::PermitNames` (r:1 w:1)
+	fn force_set_metadata() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmAssets::Asset` (r:1 w:1)
+	/// Storage: `EvmAssets::PendingOwner` (r:0 w:1)
+	fn force_transfer_ownership() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmAssets::Asset` (r:1 w:0)
+	/// Storage: `EvmAssets::Permissions` (r:0 w:1)
+	/// Storage: `EvmAssets::MinterAllowances` (r:0 w:1)
+	fn force_set_permissions() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmAssets::PausedAssets` (r:1 w:0)
+	/// Storage: `EvmAssets::FrozenAccounts` (r:1 w:0)
+	/// Storage: `EvmAssets::Asset` (r:1 w:1)
+	/// Storage: `EvmAssets::MaxSupply` (r:1 w:0)
+	/// Storage: `EvmAssets::Balances` (r:1 w:1)
+	fn force_mint() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmAssets::PausedAssets` (r:1 w:0)
+	/// Storage: `EvmAssets::FrozenAccounts` (r:1 w:0)
+	/// Storage: `EvmAssets::Balances` (r:1 w:1)
+	/// Storage: `EvmAssets::Asset` (r:1 w:1)
+	fn force_burn() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EvmAssets::Asset` (r:1 w:1)
+	/// Storage: `EvmAssets::Metadata` (r:0 w:1)
+	/// Storage: `EvmAssets::PermitNames` (r:0 w:1)
+	/// Storage: `EvmAssets::MaxSupply` (r:0 w:1)
+	/// Storage: `EvmAssets::PausedAssets` (r:0 w:1)
+	/// Storage: `EvmAssets::PendingOwner` (r:0 w:1)
+	/// Storage: `EvmAssets::AssetDeposits` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	fn force_destroy() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn force_create() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+	}
+	fn force_set_metadata() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+	}
+	fn force_transfer_ownership() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+	}
+	fn force_set_permissions() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+	}
+	fn force_mint() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+	}
+	fn force_burn() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+	}
+	fn force_destroy() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+	}
+}
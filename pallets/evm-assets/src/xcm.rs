@@ -75,3 +75,18 @@ where
 		})
 	}
 }
+
+impl<T: Config> TransferableAssets for Pallet<T> {
+	fn ensure_transferable(asset: &H160, sender: &H160, amount: u128) -> Option<DispatchResult> {
+		let asset = Self::address_to_asset_id(asset)?;
+		Some((|| {
+			ensure!(Self::asset_exists(asset), <Error<T>>::AssetNotFound);
+			Self::ensure_movable(&asset, sender)?;
+			ensure!(
+				amount <= Self::balance(&asset, sender),
+				<Error<T>>::ERC20InsufficientBalance
+			);
+			Ok(())
+		})())
+	}
+}
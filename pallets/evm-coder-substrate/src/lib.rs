@@ -39,6 +39,7 @@ use sp_weights::Weight;
 
 pub mod budget;
 pub mod execution;
+pub mod permit;
 
 pub use evm_coder::{abi, solidity_interface, types, Contract, ResultWithPostInfoOf, ToLog};
 use evm_coder::{
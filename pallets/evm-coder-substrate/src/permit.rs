@@ -0,0 +1,120 @@
+//! [EIP-2612](https://eips.ethereum.org/EIPS/eip-2612) signed approvals,
+//! shared by the fungible precompiles.
+
+use frame_support::{
+	sp_runtime::traits::UniqueSaturatedInto,
+	traits::{Get, Time},
+};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+/// `keccak256("EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")`
+const DOMAIN_TYPEHASH: [u8; 32] =
+	hex_literal::hex!("8b73c3c69bb8fe3d512ecc4cf759cc79239f7b179b0ffacaa9a75d522b39400f");
+
+/// `keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")`
+const PERMIT_TYPEHASH: [u8; 32] =
+	hex_literal::hex!("6e71edae12b1b97f4d1f60370fef10105fa2faae0126114a169c64845d6126c9");
+
+/// `keccak256("1")`
+const VERSION_HASH: [u8; 32] =
+	hex_literal::hex!("c89efdaa54c0f20c7adf612882df0950f5a951637e0307cdcb4c672f298b8bc6");
+
+/// Upper bound of the `s` signature value, see EIP-2.
+const SECP256K1_HALF_ORDER: [u8; 32] =
+	hex_literal::hex!("7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a0");
+
+/// Reason, for which a signed approval is rejected.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PermitError {
+	/// The deadline has passed.
+	Expired,
+	/// The signature is malformed or is not made by the owner.
+	InvalidSigner,
+}
+
+/// Signed approval of `spender` to spend `value` of `owner`'s tokens.
+pub struct Permit {
+	pub owner: H160,
+	pub spender: H160,
+	/// Value as signed, the precompiles clamp it only when storing the allowance.
+	pub value: U256,
+	pub nonce: U256,
+	/// Unix time in seconds.
+	pub deadline: U256,
+}
+
+fn address_word(address: &H160) -> [u8; 32] {
+	let mut word = [0; 32];
+	word[12..].copy_from_slice(address.as_bytes());
+	word
+}
+
+fn u256_word(value: U256) -> [u8; 32] {
+	let mut word = [0; 32];
+	value.to_big_endian(&mut word);
+	word
+}
+
+/// Returns the EIP-712 domain separator of the token contract with the given name.
+pub fn domain_separator<T: pallet_evm::Config>(name: &[u8], contract: &H160) -> H256 {
+	let mut data = Vec::with_capacity(32 * 5);
+	data.extend_from_slice(&DOMAIN_TYPEHASH);
+	data.extend_from_slice(&keccak_256(name));
+	data.extend_from_slice(&VERSION_HASH);
+	data.extend_from_slice(&u256_word(T::ChainId::get().into()));
+	data.extend_from_slice(&address_word(contract));
+
+	H256(keccak_256(&data))
+}
+
+impl Permit {
+	/// Returns the EIP-712 digest of the approval, which is signed by the owner.
+	pub fn digest(&self, domain_separator: &H256) -> [u8; 32] {
+		let mut struct_data = Vec::with_capacity(32 * 6);
+		struct_data.extend_from_slice(&PERMIT_TYPEHASH);
+		struct_data.extend_from_slice(&address_word(&self.owner));
+		struct_data.extend_from_slice(&address_word(&self.spender));
+		struct_data.extend_from_slice(&u256_word(self.value));
+		struct_data.extend_from_slice(&u256_word(self.nonce));
+		struct_data.extend_from_slice(&u256_word(self.deadline));
+
+		let mut digest_data = Vec::with_capacity(2 + 32 * 2);
+		digest_data.extend_from_slice(b"\x19\x01");
+		digest_data.extend_from_slice(domain_separator.as_bytes());
+		digest_data.extend_from_slice(&keccak_256(&struct_data));
+		keccak_256(&digest_data)
+	}
+
+	/// Checks that the approval is not expired and is signed by its owner.
+	pub fn verify<T: pallet_evm::Config>(
+		&self,
+		domain_separator: &H256,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> Result<(), PermitError> {
+		let now_ms: u64 = T::Timestamp::now().unique_saturated_into();
+		if self.deadline < U256::from(now_ms / 1000) {
+			return Err(PermitError::Expired);
+		}
+
+		if s.0 > SECP256K1_HALF_ORDER || !(v == 27 || v == 28) {
+			return Err(PermitError::InvalidSigner);
+		}
+		let mut signature = [0; 65];
+		signature[..32].copy_from_slice(r.as_bytes());
+		signature[32..64].copy_from_slice(s.as_bytes());
+		signature[64] = v - 27;
+
+		let public =
+			sp_io::crypto::secp256k1_ecdsa_recover(&signature, &self.digest(domain_separator))
+				.map_err(|_| PermitError::InvalidSigner)?;
+		let signer = H160::from_slice(&keccak_256(&public)[12..]);
+		if signer != self.owner || self.owner == H160::zero() {
+			return Err(PermitError::InvalidSigner);
+		}
+		Ok(())
+	}
+}
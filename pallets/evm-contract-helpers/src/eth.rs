@@ -0,0 +1,259 @@
+use evm_coder::{abi::AbiType, generate_stubgen, solidity_interface, types::Caller};
+use pallet_evm::{OnMethodCall, PrecompileHandle, PrecompileResult};
+use pallet_evm_coder_substrate::{
+	dispatch_to_evm,
+	execution::{PreDispatch, Result},
+	frontier_contract,
+};
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::vec::Vec;
+
+use crate::*;
+
+/// Code reported for the helpers address.
+/// Calls never reach it, since they are intercepted by [`HelpersOnMethodCall`],
+/// it is only needed for the address to be seen as a contract.
+const HELPERS_STUB_CODE: [u8; 5] = hex_literal::hex!("60006000fd");
+
+/// Handle for the contract helpers precompile
+pub struct ContractHelpersHandle<T: Config>(SubstrateRecorder<T>);
+
+impl<T: Config> ContractHelpersHandle<T> {
+	/// Creates a handle
+	pub fn new_with_gas_limit(gas_limit: u64) -> ContractHelpersHandle<T> {
+		Self(SubstrateRecorder::new(gas_limit))
+	}
+}
+
+impl<T: Config> WithRecorder<T> for ContractHelpersHandle<T> {
+	fn recorder(&self) -> &pallet_evm_coder_substrate::SubstrateRecorder<T> {
+		&self.0
+	}
+	fn into_recorder(self) -> pallet_evm_coder_substrate::SubstrateRecorder<T> {
+		self.0
+	}
+}
+
+impl<T: Config> Deref for ContractHelpersHandle<T> {
+	type Target = SubstrateRecorder<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+frontier_contract! {
+	macro_rules! ContractHelpersHandle_result {...}
+	impl<T: Config> Contract for ContractHelpersHandle<T> {...}
+}
+
+#[solidity_interface(name = ContractHelpers, enum(derive(PreDispatch)), enum_attr(weight))]
+impl<T: Config> ContractHelpersHandle<T> {
+	/// Get the deployer of the contract.
+	///
+	/// @param contractAddress Address of the contract.
+	/// @return Address of the owner, zero for contracts deployed before owners were recorded.
+	fn contract_owner(&self, contract_address: Address) -> Result<Address> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::contract_owner(&contract_address).unwrap_or_default())
+	}
+
+	/// Propose the sponsor of the contract.
+	/// The sponsor starts paying for the calls after `confirmSponsorship`.
+	///
+	/// Only available to the contract owner.
+	fn set_sponsor(
+		&mut self,
+		caller: Caller,
+		contract_address: Address,
+		sponsor: Address,
+	) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&contract_address, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_sponsor(&contract_address, &sponsor);
+		Ok(())
+	}
+
+	/// Make the contract pay for the calls to itself.
+	///
+	/// Only available to the contract owner.
+	fn self_sponsored_enable(&mut self, caller: Caller, contract_address: Address) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&contract_address, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_self_sponsored(&contract_address);
+		Ok(())
+	}
+
+	/// Confirm paying for the calls to the contract.
+	///
+	/// Only available to the proposed sponsor.
+	fn confirm_sponsorship(&mut self, caller: Caller, contract_address: Address) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::confirm_sponsorship(&contract_address, &caller).map_err(dispatch_to_evm::<T>)
+	}
+
+	/// Disable sponsoring of the contract.
+	///
+	/// Only available to the contract owner.
+	fn remove_sponsor(&mut self, caller: Caller, contract_address: Address) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&contract_address, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::remove_sponsor(&contract_address);
+		Ok(())
+	}
+
+	/// Get the confirmed sponsor of the contract.
+	///
+	/// @return Address of the sponsor, zero if there is none.
+	fn sponsor(&self, contract_address: Address) -> Result<Address> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::sponsor(&contract_address)
+			.and_then(|state| state.confirmed().copied())
+			.unwrap_or_default())
+	}
+
+	/// Check whether the contract has a confirmed sponsor.
+	fn has_sponsor(&self, contract_address: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::sponsor(&contract_address)
+			.map_or(false, |state| state.confirmed().is_some()))
+	}
+
+	/// Check whether the contract has a sponsor, which has not confirmed the sponsorship yet.
+	fn has_pending_sponsor(&self, contract_address: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::sponsor(&contract_address)
+			.map_or(false, |state| state.confirmed().is_none()))
+	}
+
+	/// Get the minimum number of blocks between sponsored calls of the same caller.
+	fn sponsoring_rate_limit(&self, contract_address: Address) -> Result<u32> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::sponsoring_rate_limit(&contract_address).unique_saturated_into())
+	}
+
+	/// Set the minimum number of blocks between sponsored calls of the same caller.
+	///
+	/// Only available to the contract owner.
+	fn set_sponsoring_rate_limit(
+		&mut self,
+		caller: Caller,
+		contract_address: Address,
+		rate_limit: u32,
+	) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&contract_address, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::set_sponsoring_rate_limit(&contract_address, rate_limit.into());
+		Ok(())
+	}
+
+	/// Check whether only allowlisted callers are sponsored.
+	fn allowlist_enabled(&self, contract_address: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::allowlist_enabled(&contract_address))
+	}
+
+	/// Sponsor only allowlisted callers and the owner, or everyone.
+	///
+	/// Only available to the contract owner.
+	fn toggle_allowlist(
+		&mut self,
+		caller: Caller,
+		contract_address: Address,
+		enabled: bool,
+	) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&contract_address, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::toggle_allowlist(&contract_address, enabled);
+		Ok(())
+	}
+
+	/// Check whether the user is in the allowlist of the contract.
+	fn allowed(&self, contract_address: Address, user: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::allowed(&contract_address, &user))
+	}
+
+	/// Add the user to the allowlist of the contract, or remove it.
+	///
+	/// Only available to the contract owner.
+	fn toggle_allowed(
+		&mut self,
+		caller: Caller,
+		contract_address: Address,
+		user: Address,
+		is_allowed: bool,
+	) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&contract_address, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::toggle_allowed(&contract_address, &user, is_allowed);
+		Ok(())
+	}
+
+	/// Check whether the deployer is approved by the factory contract.
+	fn deployer_approved(&self, factory: Address, deployer: Address) -> Result<bool> {
+		self.consume_store_reads(1)?;
+		Ok(<Pallet<T>>::deployer_approved(&factory, &deployer))
+	}
+
+	/// Approve the deployer, so its contract creation may be sponsored
+	/// by the rules tied to the factory, or revoke the approval.
+	///
+	/// Only available to the factory owner.
+	fn toggle_deployer(
+		&mut self,
+		caller: Caller,
+		factory: Address,
+		deployer: Address,
+		approved: bool,
+	) -> Result<()> {
+		self.consume_store_reads(1)?;
+		self.consume_store_writes(1)?;
+
+		<Pallet<T>>::ensure_owner(&factory, &caller).map_err(dispatch_to_evm::<T>)?;
+		<Pallet<T>>::toggle_deployer(&factory, &deployer, approved);
+		Ok(())
+	}
+}
+
+/// Implements [`OnMethodCall`], which delegates call to [`ContractHelpersHandle`]
+pub struct HelpersOnMethodCall<T: Config>(PhantomData<*const T>);
+impl<T: Config> OnMethodCall<T> for HelpersOnMethodCall<T> {
+	fn is_reserved(contract: &H160) -> bool {
+		contract == &T::ContractAddress::get()
+	}
+
+	fn is_used(contract: &H160) -> bool {
+		contract == &T::ContractAddress::get()
+	}
+
+	fn call(handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		if handle.code_address() != T::ContractAddress::get() {
+			return None;
+		}
+
+		let helpers_handle = <ContractHelpersHandle<T>>::new_with_gas_limit(handle.remaining_gas());
+		pallet_evm_coder_substrate::call::<_, ContractHelpersCall<_>, _, _>(handle, helpers_handle)
+	}
+
+	fn get_code(contract: &H160) -> Option<Vec<u8>> {
+		Self::is_used(contract).then(|| HELPERS_STUB_CODE.to_vec())
+	}
+}
+
+generate_stubgen!(gen_impl, ContractHelpersCall<()>, true);
+generate_stubgen!(gen_iface, ContractHelpersCall<()>, false);
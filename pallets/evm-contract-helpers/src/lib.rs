@@ -0,0 +1,311 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::ops::Deref;
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use pallet_evm::{account::CrossAccountId, OnCreate};
+use pallet_evm_coder_substrate::{SubstrateRecorder, WithRecorder};
+use pallet_evm_transaction_payment::CallContext;
+use sp_core::H160;
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::marker::PhantomData;
+use up_sponsorship::SponsorshipHandler;
+
+pub mod eth;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub(crate) type Address = H160;
+
+/// Sponsor of a contract.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum SponsorshipState {
+	/// The sponsor is set by the owner, but has not confirmed it yet.
+	Unconfirmed(Address),
+	/// The sponsor pays for the calls to the contract.
+	Confirmed(Address),
+}
+
+impl SponsorshipState {
+	pub fn confirmed(&self) -> Option<&Address> {
+		match self {
+			Self::Confirmed(sponsor) => Some(sponsor),
+			Self::Unconfirmed(_) => None,
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Only the contract owner can do this.
+		NoPermission,
+		/// The caller is not the pending sponsor of the contract.
+		NoPendingSponsor,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Contract was deployed by `owner`.
+		ContractOwnerSet { contract: Address, owner: Address },
+		/// Contract owner has proposed the sponsor.
+		SponsorSet { contract: Address, sponsor: Address },
+		/// The sponsor has confirmed paying for the contract calls.
+		SponsorshipConfirmed { contract: Address, sponsor: Address },
+		/// Sponsoring of the contract was disabled.
+		SponsorRemoved { contract: Address },
+		/// Minimum number of blocks between sponsored calls of the same caller was changed.
+		SponsoringRateLimitSet {
+			contract: Address,
+			rate_limit: BlockNumberFor<T>,
+		},
+		/// Allowlist of the contract was enabled or disabled.
+		AllowlistToggled { contract: Address, enabled: bool },
+		/// Caller was added to or removed from the allowlist of the contract.
+		AllowedToggled {
+			contract: Address,
+			user: Address,
+			allowed: bool,
+		},
+		/// Deployer was approved by the factory contract, or the approval was revoked.
+		DeployerToggled {
+			factory: Address,
+			deployer: Address,
+			approved: bool,
+		},
+	}
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_evm::Config + pallet_evm_coder_substrate::Config
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Address, under which the contract helpers precompile will be available
+		#[pallet::constant]
+		type ContractAddress: Get<H160>;
+
+		/// Rate limit of the contracts, whose owners have not set one.
+		#[pallet::constant]
+		type DefaultSponsoringRateLimit: Get<BlockNumberFor<Self>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	/// Deployer of a contract.
+	pub(super) type Owner<T: Config> = StorageMap<_, Blake2_128Concat, Address, Address>;
+
+	#[pallet::storage]
+	/// Sponsor of a contract.
+	pub(super) type Sponsor<T: Config> = StorageMap<_, Blake2_128Concat, Address, SponsorshipState>;
+
+	#[pallet::type_value]
+	pub(super) fn DefaultRateLimit<T: Config>() -> BlockNumberFor<T> {
+		T::DefaultSponsoringRateLimit::get()
+	}
+
+	#[pallet::storage]
+	/// Minimum number of blocks between sponsored calls of the same caller.
+	pub(super) type SponsoringRateLimit<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		Address,
+		BlockNumberFor<T>,
+		ValueQuery,
+		DefaultRateLimit<T>,
+	>;
+
+	#[pallet::storage]
+	/// Contracts, which only sponsor calls of allowlisted callers.
+	pub(super) type AllowlistEnabled<T: Config> = StorageMap<_, Blake2_128Concat, Address, ()>;
+
+	#[pallet::storage]
+	/// Callers, allowed to be sponsored by a contract.
+	pub(super) type Allowlist<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Address, Blake2_128Concat, Address, ()>;
+
+	#[pallet::storage]
+	/// Deployers approved by a factory contract,
+	/// which may have their contract creation sponsored.
+	pub(super) type ApprovedDeployers<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Address, Blake2_128Concat, Address, ()>;
+
+	#[pallet::storage]
+	/// Block of the last sponsored call of a caller to a contract.
+	pub(super) type LastSponsored<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Address,
+		Blake2_128Concat,
+		Address,
+		BlockNumberFor<T>,
+	>;
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn contract_owner(contract: &Address) -> Option<Address> {
+		<Owner<T>>::get(contract)
+	}
+
+	pub fn ensure_owner(contract: &Address, who: &Address) -> DispatchResult {
+		ensure!(
+			<Owner<T>>::get(contract).as_ref() == Some(who),
+			<Error<T>>::NoPermission
+		);
+		Ok(())
+	}
+
+	pub fn sponsor(contract: &Address) -> Option<SponsorshipState> {
+		<Sponsor<T>>::get(contract)
+	}
+
+	/// Proposes `sponsor` for the contract, it should confirm it
+	/// with [`Self::confirm_sponsorship`] before paying for the calls.
+	pub fn set_sponsor(contract: &Address, sponsor: &Address) {
+		<Sponsor<T>>::insert(contract, SponsorshipState::Unconfirmed(*sponsor));
+		Self::deposit_event(Event::SponsorSet {
+			contract: *contract,
+			sponsor: *sponsor,
+		});
+	}
+
+	/// Makes the contract pay for the calls to itself, no confirmation is needed.
+	pub fn set_self_sponsored(contract: &Address) {
+		<Sponsor<T>>::insert(contract, SponsorshipState::Confirmed(*contract));
+		Self::deposit_event(Event::SponsorshipConfirmed {
+			contract: *contract,
+			sponsor: *contract,
+		});
+	}
+
+	pub fn confirm_sponsorship(contract: &Address, sponsor: &Address) -> DispatchResult {
+		ensure!(
+			<Sponsor<T>>::get(contract) == Some(SponsorshipState::Unconfirmed(*sponsor)),
+			<Error<T>>::NoPendingSponsor
+		);
+
+		<Sponsor<T>>::insert(contract, SponsorshipState::Confirmed(*sponsor));
+		Self::deposit_event(Event::SponsorshipConfirmed {
+			contract: *contract,
+			sponsor: *sponsor,
+		});
+		Ok(())
+	}
+
+	pub fn remove_sponsor(contract: &Address) {
+		<Sponsor<T>>::remove(contract);
+		Self::deposit_event(Event::SponsorRemoved {
+			contract: *contract,
+		});
+	}
+
+	pub fn sponsoring_rate_limit(contract: &Address) -> BlockNumberFor<T> {
+		<SponsoringRateLimit<T>>::get(contract)
+	}
+
+	pub fn set_sponsoring_rate_limit(contract: &Address, rate_limit: BlockNumberFor<T>) {
+		<SponsoringRateLimit<T>>::insert(contract, rate_limit);
+		Self::deposit_event(Event::SponsoringRateLimitSet {
+			contract: *contract,
+			rate_limit,
+		});
+	}
+
+	pub fn allowlist_enabled(contract: &Address) -> bool {
+		<AllowlistEnabled<T>>::contains_key(contract)
+	}
+
+	pub fn toggle_allowlist(contract: &Address, enabled: bool) {
+		if enabled {
+			<AllowlistEnabled<T>>::insert(contract, ());
+		} else {
+			<AllowlistEnabled<T>>::remove(contract);
+		}
+		Self::deposit_event(Event::AllowlistToggled {
+			contract: *contract,
+			enabled,
+		});
+	}
+
+	pub fn allowed(contract: &Address, user: &Address) -> bool {
+		<Allowlist<T>>::contains_key(contract, user)
+	}
+
+	pub fn toggle_allowed(contract: &Address, user: &Address, allowed: bool) {
+		if allowed {
+			<Allowlist<T>>::insert(contract, user, ());
+		} else {
+			<Allowlist<T>>::remove(contract, user);
+		}
+		Self::deposit_event(Event::AllowedToggled {
+			contract: *contract,
+			user: *user,
+			allowed,
+		});
+	}
+
+	pub fn deployer_approved(factory: &Address, deployer: &Address) -> bool {
+		<ApprovedDeployers<T>>::contains_key(factory, deployer)
+	}
+
+	pub fn toggle_deployer(factory: &Address, deployer: &Address, approved: bool) {
+		if approved {
+			<ApprovedDeployers<T>>::insert(factory, deployer, ());
+		} else {
+			<ApprovedDeployers<T>>::remove(factory, deployer);
+		}
+		Self::deposit_event(Event::DeployerToggled {
+			factory: *factory,
+			deployer: *deployer,
+			approved,
+		});
+	}
+}
+
+impl<T: Config> OnCreate<T> for Pallet<T> {
+	fn on_create(owner: H160, contract: H160) {
+		<Owner<T>>::insert(contract, owner);
+		Self::deposit_event(Event::ContractOwnerSet { contract, owner });
+	}
+}
+
+impl<T: Config> SponsorshipHandler<T::CrossAccountId, CallContext> for Pallet<T> {
+	fn get_sponsor(who: &T::CrossAccountId, call: &CallContext) -> Option<T::CrossAccountId> {
+		let contract = call.contract_address;
+		let caller = who.as_eth();
+		let sponsor = *<Sponsor<T>>::get(contract)?.confirmed()?;
+
+		if Self::allowlist_enabled(&contract)
+			&& !Self::allowed(&contract, caller)
+			&& Self::ensure_owner(&contract, caller).is_err()
+		{
+			return None;
+		}
+
+		let now = <frame_system::Pallet<T>>::block_number();
+		let rate_limit = Self::sponsoring_rate_limit(&contract);
+		if !rate_limit.is_zero() {
+			if let Some(last) = <LastSponsored<T>>::get(contract, caller) {
+				if now.saturating_sub(last) < rate_limit {
+					return None;
+				}
+			}
+			<LastSponsored<T>>::insert(contract, caller, now);
+		}
+
+		Some(T::CrossAccountId::from_eth(sponsor))
+	}
+}
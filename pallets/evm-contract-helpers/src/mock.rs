@@ -0,0 +1,145 @@
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU128, ConstU32, ConstU64},
+	weights::Weight,
+};
+use pallet_evm::{
+	account::{BasicCrossAccountId, CrossAccountId as _},
+	EnsureAddressNever, HashedAddressMapping, SubstrateBlockHashMapping,
+};
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+
+use crate::{self as pallet_evm_contract_helpers, *};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type CrossAccountId = BasicCrossAccountId<Test>;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		EVM: pallet_evm,
+		EvmCoderSubstrate: pallet_evm_coder_substrate,
+		EvmContractHelpers: pallet_evm_contract_helpers,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u32::MAX);
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type CrossAccountId = CrossAccountId;
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BackwardsAddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BlockGasLimit = BlockGasLimit;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressNever<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type OnMethodCall = ();
+	type OnCreate = EvmContractHelpers;
+	type ChainId = ConstU64<8888>;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type FindAuthor = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type GasLimitPovSizeRatio = ConstU64<4>;
+	type OnCheckEvmTransaction = ();
+}
+
+impl pallet_evm_coder_substrate::Config for Test {}
+
+pub const CONTRACT: H160 = H160([0x10; 20]);
+pub const OWNER: H160 = H160([0x20; 20]);
+pub const SPONSOR: H160 = H160([0x30; 20]);
+pub const CALLER: H160 = H160([0x40; 20]);
+pub const RATE_LIMIT: u64 = 2;
+
+parameter_types! {
+	pub const ContractAddress: H160 = H160([0xBA; 20]);
+}
+
+impl pallet_evm_contract_helpers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ContractAddress = ContractAddress;
+	type DefaultSponsoringRateLimit = ConstU64<RATE_LIMIT>;
+}
+
+pub fn eth(address: H160) -> CrossAccountId {
+	CrossAccountId::from_eth(address)
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig::default().build_storage().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
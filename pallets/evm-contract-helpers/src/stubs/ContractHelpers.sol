@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: OTHER
+// This code is automatically generated
+
+pragma solidity >=0.8.0 <0.9.0;
+
+/// @dev common stubs holder
+contract Dummy {
+	uint8 dummy;
+	string stub_error = "this contract is implemented in native";
+}
+
+contract ERC165 is Dummy {
+	function supportsInterface(bytes4 interfaceID) external view returns (bool) {
+		require(false, stub_error);
+		interfaceID;
+		return true;
+	}
+}
+
+/// @dev the ERC-165 identifier for this interface is 0x8c6bcf08
+contract ContractHelpers is Dummy, ERC165 {
+	/// Get the deployer of the contract.
+	///
+	/// @param contractAddress Address of the contract.
+	/// @return Address of the owner, zero for contracts deployed before owners were recorded.
+	/// @dev EVM selector for this function is: 0x5152b14c,
+	///  or in textual repr: contractOwner(address)
+	function contractOwner(address contractAddress) public view returns (address) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return 0x0000000000000000000000000000000000000000;
+	}
+
+	/// Propose the sponsor of the contract.
+	/// The sponsor starts paying for the calls after `confirmSponsorship`.
+	///
+	/// Only available to the contract owner.
+	/// @dev EVM selector for this function is: 0xf01fba93,
+	///  or in textual repr: setSponsor(address,address)
+	function setSponsor(address contractAddress, address sponsor) public {
+		require(false, stub_error);
+		contractAddress;
+		sponsor;
+		dummy = 0;
+	}
+
+	/// Make the contract pay for the calls to itself.
+	///
+	/// Only available to the contract owner.
+	/// @dev EVM selector for this function is: 0x89f7d9ae,
+	///  or in textual repr: selfSponsoredEnable(address)
+	function selfSponsoredEnable(address contractAddress) public {
+		require(false, stub_error);
+		contractAddress;
+		dummy = 0;
+	}
+
+	/// Confirm paying for the calls to the contract.
+	///
+	/// Only available to the proposed sponsor.
+	/// @dev EVM selector for this function is: 0xabc00001,
+	///  or in textual repr: confirmSponsorship(address)
+	function confirmSponsorship(address contractAddress) public {
+		require(false, stub_error);
+		contractAddress;
+		dummy = 0;
+	}
+
+	/// Disable sponsoring of the contract.
+	///
+	/// Only available to the contract owner.
+	/// @dev EVM selector for this function is: 0xef784250,
+	///  or in textual repr: removeSponsor(address)
+	function removeSponsor(address contractAddress) public {
+		require(false, stub_error);
+		contractAddress;
+		dummy = 0;
+	}
+
+	/// Get the confirmed sponsor of the contract.
+	///
+	/// @return Address of the sponsor, zero if there is none.
+	/// @dev EVM selector for this function is: 0x766c4f37,
+	///  or in textual repr: sponsor(address)
+	function sponsor(address contractAddress) public view returns (address) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return 0x0000000000000000000000000000000000000000;
+	}
+
+	/// Check whether the contract has a confirmed sponsor.
+	/// @dev EVM selector for this function is: 0x97418603,
+	///  or in textual repr: hasSponsor(address)
+	function hasSponsor(address contractAddress) public view returns (bool) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return false;
+	}
+
+	/// Check whether the contract has a sponsor, which has not confirmed the sponsorship yet.
+	/// @dev EVM selector for this function is: 0x39b9b242,
+	///  or in textual repr: hasPendingSponsor(address)
+	function hasPendingSponsor(address contractAddress) public view returns (bool) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return false;
+	}
+
+	/// Get the minimum number of blocks between sponsored calls of the same caller.
+	/// @dev EVM selector for this function is: 0xf29694d8,
+	///  or in textual repr: sponsoringRateLimit(address)
+	function sponsoringRateLimit(address contractAddress) public view returns (uint32) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return 0;
+	}
+
+	/// Set the minimum number of blocks between sponsored calls of the same caller.
+	///
+	/// Only available to the contract owner.
+	/// @dev EVM selector for this function is: 0x77b6c908,
+	///  or in textual repr: setSponsoringRateLimit(address,uint32)
+	function setSponsoringRateLimit(address contractAddress, uint32 rateLimit) public {
+		require(false, stub_error);
+		contractAddress;
+		rateLimit;
+		dummy = 0;
+	}
+
+	/// Check whether only allowlisted callers are sponsored.
+	/// @dev EVM selector for this function is: 0xc772ef6c,
+	///  or in textual repr: allowlistEnabled(address)
+	function allowlistEnabled(address contractAddress) public view returns (bool) {
+		require(false, stub_error);
+		contractAddress;
+		dummy;
+		return false;
+	}
+
+	/// Sponsor only allowlisted callers and the owner, or everyone.
+	///
+	/// Only available to the contract owner.
+	/// @dev EVM selector for this function is: 0x36de20f5,
+	///  or in textual repr: toggleAllowlist(address,bool)
+	function toggleAllowlist(address contractAddress, bool enabled) public {
+		require(false, stub_error);
+		contractAddress;
+		enabled;
+		dummy = 0;
+	}
+
+	/// Check whether the user is in the allowlist of the contract.
+	/// @dev EVM selector for this function is: 0x5c658165,
+	///  or in textual repr: allowed(address,address)
+	function allowed(address contractAddress, address user) public view returns (bool) {
+		require(false, stub_error);
+		contractAddress;
+		user;
+		dummy;
+		return false;
+	}
+
+	/// Add the user to the allowlist of the contract, or remove it.
+	///
+	/// Only available to the contract owner.
+	/// @dev EVM selector for this function is: 0x4706cc1c,
+	///  or in textual repr: toggleAllowed(address,address,bool)
+	function toggleAllowed(
+		address contractAddress,
+		address user,
+		bool isAllowed
+	) public {
+		require(false, stub_error);
+		contractAddress;
+		user;
+		isAllowed;
+		dummy = 0;
+	}
+
+	/// Check whether the deployer is approved by the factory contract.
+	/// @dev EVM selector for this function is: 0x8475ce28,
+	///  or in textual repr: deployerApproved(address,address)
+	function deployerApproved(address factory, address deployer) public view returns (bool) {
+		require(false, stub_error);
+		factory;
+		deployer;
+		dummy;
+		return false;
+	}
+
+	/// Approve the deployer, so its contract creation may be sponsored
+	/// by the rules tied to the factory, or revoke the approval.
+	///
+	/// Only available to the factory owner.
+	/// @dev EVM selector for this function is: 0xd3673546,
+	///  or in textual repr: toggleDeployer(address,address,bool)
+	function toggleDeployer(
+		address factory,
+		address deployer,
+		bool approved
+	) public {
+		require(false, stub_error);
+		factory;
+		deployer;
+		approved;
+		dummy = 0;
+	}
+}
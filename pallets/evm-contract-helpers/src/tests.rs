@@ -0,0 +1,161 @@
+use frame_support::{assert_noop, assert_ok};
+use pallet_evm::account::CrossAccountId as _;
+use sp_core::U256;
+
+use crate::{mock::*, *};
+
+fn call_sponsor(caller: H160) -> Option<H160> {
+	let context = CallContext {
+		contract_address: CONTRACT,
+		input: vec![],
+		max_fee: U256::from(1),
+	};
+	<EvmContractHelpers as SponsorshipHandler<
+		<Test as pallet_evm::Config>::CrossAccountId,
+		CallContext,
+	>>::get_sponsor(&eth(caller), &context)
+	.map(|sponsor| *sponsor.as_eth())
+}
+
+fn deploy() {
+	EvmContractHelpers::on_create(OWNER, CONTRACT);
+}
+
+#[test]
+fn deployer_becomes_owner() {
+	new_test_ext().execute_with(|| {
+		deploy();
+		System::assert_last_event(
+			Event::ContractOwnerSet {
+				contract: CONTRACT,
+				owner: OWNER,
+			}
+			.into(),
+		);
+		assert_eq!(EvmContractHelpers::contract_owner(&CONTRACT), Some(OWNER));
+		assert_ok!(EvmContractHelpers::ensure_owner(&CONTRACT, &OWNER));
+		assert_noop!(
+			EvmContractHelpers::ensure_owner(&CONTRACT, &CALLER),
+			<Error<Test>>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn sponsor_pays_only_after_confirmation() {
+	new_test_ext().execute_with(|| {
+		deploy();
+		EvmContractHelpers::set_sponsor(&CONTRACT, &SPONSOR);
+		assert_eq!(call_sponsor(CALLER), None);
+
+		assert_noop!(
+			EvmContractHelpers::confirm_sponsorship(&CONTRACT, &CALLER),
+			<Error<Test>>::NoPendingSponsor
+		);
+		assert_ok!(EvmContractHelpers::confirm_sponsorship(&CONTRACT, &SPONSOR));
+		System::assert_last_event(
+			Event::SponsorshipConfirmed {
+				contract: CONTRACT,
+				sponsor: SPONSOR,
+			}
+			.into(),
+		);
+		assert_eq!(call_sponsor(CALLER), Some(SPONSOR));
+		// Confirmed sponsorship can't be confirmed again
+		assert_noop!(
+			EvmContractHelpers::confirm_sponsorship(&CONTRACT, &SPONSOR),
+			<Error<Test>>::NoPendingSponsor
+		);
+
+		EvmContractHelpers::remove_sponsor(&CONTRACT);
+		System::set_block_number(1 + RATE_LIMIT);
+		assert_eq!(call_sponsor(CALLER), None);
+	});
+}
+
+#[test]
+fn self_sponsored_contract_pays_for_itself() {
+	new_test_ext().execute_with(|| {
+		deploy();
+		EvmContractHelpers::set_self_sponsored(&CONTRACT);
+		assert_eq!(
+			EvmContractHelpers::sponsor(&CONTRACT),
+			Some(SponsorshipState::Confirmed(CONTRACT))
+		);
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+	});
+}
+
+#[test]
+fn rate_limit_is_per_caller() {
+	new_test_ext().execute_with(|| {
+		deploy();
+		EvmContractHelpers::set_self_sponsored(&CONTRACT);
+		assert_eq!(
+			EvmContractHelpers::sponsoring_rate_limit(&CONTRACT),
+			RATE_LIMIT
+		);
+
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+		assert_eq!(call_sponsor(CALLER), None);
+		assert_eq!(call_sponsor(OWNER), Some(CONTRACT));
+
+		System::set_block_number(1 + RATE_LIMIT);
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+
+		// Zero rate limit doesn't limit the calls
+		EvmContractHelpers::set_sponsoring_rate_limit(&CONTRACT, 0);
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+	});
+}
+
+#[test]
+fn allowlist_limits_sponsored_callers() {
+	new_test_ext().execute_with(|| {
+		deploy();
+		EvmContractHelpers::set_self_sponsored(&CONTRACT);
+		EvmContractHelpers::set_sponsoring_rate_limit(&CONTRACT, 0);
+
+		EvmContractHelpers::toggle_allowlist(&CONTRACT, true);
+		assert_eq!(call_sponsor(CALLER), None);
+		// The owner is always allowed
+		assert_eq!(call_sponsor(OWNER), Some(CONTRACT));
+
+		EvmContractHelpers::toggle_allowed(&CONTRACT, &CALLER, true);
+		assert!(EvmContractHelpers::allowed(&CONTRACT, &CALLER));
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+
+		EvmContractHelpers::toggle_allowed(&CONTRACT, &CALLER, false);
+		assert_eq!(call_sponsor(CALLER), None);
+
+		EvmContractHelpers::toggle_allowlist(&CONTRACT, false);
+		assert_eq!(call_sponsor(CALLER), Some(CONTRACT));
+	});
+}
+
+#[test]
+fn deployer_approval_is_separate_from_allowlist() {
+	new_test_ext().execute_with(|| {
+		deploy();
+		EvmContractHelpers::toggle_allowed(&CONTRACT, &CALLER, true);
+		assert!(!EvmContractHelpers::deployer_approved(&CONTRACT, &CALLER));
+
+		EvmContractHelpers::toggle_deployer(&CONTRACT, &CALLER, true);
+		System::assert_last_event(
+			Event::DeployerToggled {
+				factory: CONTRACT,
+				deployer: CALLER,
+				approved: true,
+			}
+			.into(),
+		);
+		assert!(EvmContractHelpers::deployer_approved(&CONTRACT, &CALLER));
+
+		EvmContractHelpers::toggle_allowed(&CONTRACT, &CALLER, false);
+		assert!(EvmContractHelpers::deployer_approved(&CONTRACT, &CALLER));
+
+		EvmContractHelpers::toggle_deployer(&CONTRACT, &CALLER, false);
+		assert!(!EvmContractHelpers::deployer_approved(&CONTRACT, &CALLER));
+	});
+}
@@ -0,0 +1,412 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	traits::{ContainsPair, Currency, OnRuntimeUpgrade, ReservableCurrency, WithdrawReasons},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use pallet_evm::account::CrossAccountId;
+use pallet_evm_transaction_payment::{CallContext, CreateContext, ExtrinsicContext};
+use sp_core::H160;
+use sp_runtime::{
+	traits::{CheckedSub, SaturatedConversion, Saturating, Zero},
+	ArithmeticError,
+};
+use sp_std::{marker::PhantomData, prelude::*};
+use up_sponsorship::SponsorshipHandler;
+
+pub mod migration;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod types;
+use types::*;
+
+pub(crate) const LOG_TARGET: &str = "runtime::evm-sponsorship";
+
+pub type SponsorshipRuleOf<T> =
+	SponsorshipRule<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+pub(crate) type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_system::pallet_prelude::*;
+
+	use super::*;
+
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The rule does not exist.
+		RuleNotFound,
+		/// Only the sponsor of the rule or the `ManagerOrigin` can manage it.
+		NotSponsor,
+		/// The target has reached `MaxRulesPerTarget`.
+		TooManyRules,
+		/// The budget period should not be zero if the budget is set.
+		ZeroPeriod,
+		/// Signed origins can only create rules for the targets they control.
+		NotTargetOwner,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A sponsorship rule was created.
+		RuleCreated {
+			rule_id: RuleId,
+			target: SponsoredTarget,
+			sponsor: T::AccountId,
+		},
+		/// A sponsorship rule was removed.
+		RuleRemoved { rule_id: RuleId },
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_evm::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin which may manage rules with any sponsor.
+		type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The maximum number of rules per target, bounds the work done on every sponsored transaction.
+		#[pallet::constant]
+		type MaxRulesPerTarget: Get<u32>;
+
+		/// The currency in which rule deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the sponsor for a rule created by a signed origin.
+		#[pallet::constant]
+		type RuleDeposit: Get<BalanceOf<Self>>;
+
+		/// Pairs of a factory and a deployer approved by it,
+		/// rules sponsoring contract creation only apply to the approved deployers.
+		type ApprovedDeployers: ContainsPair<H160, H160>;
+
+		/// Pairs of a contract and its owner,
+		/// signed origins can only create rules for the contracts they own.
+		type ContractOwners: ContainsPair<H160, H160>;
+	}
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	/// Sponsorship rules.
+	pub(super) type Rules<T: Config> = StorageMap<_, Twox64Concat, RuleId, SponsorshipRuleOf<T>>;
+
+	#[pallet::storage]
+	/// Rules of a target.
+	pub(super) type TargetRules<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, TargetKey, Twox64Concat, RuleId, ()>;
+
+	#[pallet::storage]
+	/// Number of rules of a target.
+	pub(super) type TargetRuleCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, TargetKey, u32, ValueQuery>;
+
+	#[pallet::storage]
+	/// The id which will be given to the next rule.
+	pub(super) type NextRuleId<T: Config> = StorageValue<_, RuleId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Block of the last sponsored call of a caller.
+	pub(super) type LastSponsored<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, RuleId, Blake2_128Concat, H160, BlockNumberFor<T>>;
+
+	#[pallet::storage]
+	/// Fees paid under a rule in the current budget period.
+	pub(super) type Budgets<T: Config> =
+		StorageMap<_, Twox64Concat, RuleId, BudgetUsage<BlockNumberFor<T>>, ValueQuery>;
+
+	#[pallet::storage]
+	/// Amount reserved from the sponsor of a rule created by a signed origin.
+	pub(super) type RuleDeposits<T: Config> = StorageMap<_, Twox64Concat, RuleId, BalanceOf<T>>;
+
+	#[pallet::storage]
+	/// Removed rules, whose [`LastSponsored`] entries are not cleared yet.
+	pub(super) type RemovedRules<T: Config> = StorageMap<_, Twox64Concat, RuleId, ()>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Clears the [`LastSponsored`] entries of a removed rule, as many as fit in the block.
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let db_weight = T::DbWeight::get();
+			// Looking up the rule and forgetting it once it is cleared
+			let base = db_weight.reads_writes(1, 1);
+			let limit = remaining_weight.saturating_sub(base).ref_time()
+				/ db_weight.writes(1).ref_time().max(1);
+			if remaining_weight.any_lt(base) || limit == 0 {
+				return Weight::zero();
+			}
+
+			let Some(rule_id) = <RemovedRules<T>>::iter_keys().next() else {
+				return db_weight.reads(1);
+			};
+			let result =
+				<LastSponsored<T>>::clear_prefix(rule_id, limit.min(u32::MAX as u64) as u32, None);
+			if result.maybe_cursor.is_none() {
+				<RemovedRules<T>>::remove(rule_id);
+			}
+
+			base.saturating_add(db_weight.reads_writes(result.loops as u64, result.unique as u64))
+		}
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Rules created at genesis, without deposits.
+		pub rules: Vec<SponsorshipRuleOf<T>>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for rule in &self.rules {
+				<Pallet<T>>::insert_rule(rule.clone()).expect("genesis rules should be valid");
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a sponsorship rule.
+		///
+		/// Signed origins can only create rules sponsored by themselves for the targets
+		/// they control, `RuleDeposit` is reserved from them until the rule is removed.
+		/// `ManagerOrigin` can create rules with any sponsor for any target.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 6))]
+		pub fn create_rule(origin: OriginFor<T>, rule: SponsorshipRuleOf<T>) -> DispatchResult {
+			let deposit = match T::ManagerOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => {
+					let who = ensure_signed(origin)?;
+					ensure!(who == rule.sponsor, <Error<T>>::NotSponsor);
+					ensure!(
+						Self::controls(&who, &rule.target),
+						<Error<T>>::NotTargetOwner
+					);
+					Some(T::RuleDeposit::get())
+				}
+			};
+
+			let sponsor = rule.sponsor.clone();
+			let rule_id = Self::insert_rule(rule)?;
+			if let Some(deposit) = deposit {
+				T::Currency::reserve(&sponsor, deposit)?;
+				<RuleDeposits<T>>::insert(rule_id, deposit);
+			}
+			Ok(())
+		}
+
+		/// Remove a sponsorship rule.
+		///
+		/// Available to the sponsor of the rule and `ManagerOrigin`.
+		/// The deposit is returned to the sponsor.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::DbWeight::get().reads_writes(3, 7))]
+		pub fn remove_rule(origin: OriginFor<T>, rule_id: RuleId) -> DispatchResult {
+			let rule = <Rules<T>>::get(rule_id).ok_or(<Error<T>>::RuleNotFound)?;
+			if let Err(origin) = T::ManagerOrigin::try_origin(origin) {
+				let who = ensure_signed(origin)?;
+				ensure!(who == rule.sponsor, <Error<T>>::NotSponsor);
+			}
+
+			Self::remove(rule_id, &rule);
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn rule(rule_id: RuleId) -> Option<SponsorshipRuleOf<T>> {
+		<Rules<T>>::get(rule_id)
+	}
+
+	pub(crate) fn insert_rule(rule: SponsorshipRuleOf<T>) -> Result<RuleId, DispatchError> {
+		ensure!(
+			rule.budget.is_none() || !rule.period.is_zero(),
+			<Error<T>>::ZeroPeriod
+		);
+		let key = rule.target.key();
+		let count = <TargetRuleCount<T>>::get(key);
+		ensure!(
+			count < T::MaxRulesPerTarget::get(),
+			<Error<T>>::TooManyRules
+		);
+
+		let rule_id = <NextRuleId<T>>::get();
+		<NextRuleId<T>>::set(rule_id.checked_add(1).ok_or(ArithmeticError::Overflow)?);
+		<TargetRuleCount<T>>::insert(key, count + 1);
+		<TargetRules<T>>::insert(key, rule_id, ());
+
+		Self::deposit_event(Event::RuleCreated {
+			rule_id,
+			target: rule.target.clone(),
+			sponsor: rule.sponsor.clone(),
+		});
+		<Rules<T>>::insert(rule_id, rule);
+
+		Ok(rule_id)
+	}
+
+	/// Contracts are controlled by their owners, contract creation by the deployer itself.
+	/// Extrinsics are not controlled by anyone, their rules are only created by `ManagerOrigin`.
+	fn controls(who: &T::AccountId, target: &SponsoredTarget) -> bool {
+		let who = T::CrossAccountId::from_sub(who.clone());
+		match target {
+			SponsoredTarget::Contract { address, .. } => {
+				T::ContractOwners::contains(address, who.as_eth())
+			}
+			SponsoredTarget::Create { deployer, .. } => deployer == who.as_eth(),
+			SponsoredTarget::Extrinsic { .. } => false,
+		}
+	}
+
+	fn remove(rule_id: RuleId, rule: &SponsorshipRuleOf<T>) {
+		<Rules<T>>::remove(rule_id);
+		let key = rule.target.key();
+		<TargetRules<T>>::remove(key, rule_id);
+		<TargetRuleCount<T>>::mutate_exists(key, |count| {
+			*count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+		});
+		<Budgets<T>>::remove(rule_id);
+		if let Some(deposit) = <RuleDeposits<T>>::take(rule_id) {
+			T::Currency::unreserve(&rule.sponsor, deposit);
+		}
+		// Rule ids are never reused, the entries are cleared in `on_idle`
+		if !rule.rate_limit.is_zero() {
+			<RemovedRules<T>>::insert(rule_id, ());
+		}
+
+		Self::deposit_event(Event::RuleRemoved { rule_id });
+	}
+
+	/// Returns the sponsor of the first rule of the target, which matches the input
+	/// and allows sponsoring the caller.
+	///
+	/// Rules whose sponsor can't pay the fee are skipped, if none is left the caller pays.
+	fn find_sponsor(
+		key: TargetKey,
+		input: &[u8],
+		caller: &H160,
+		fee: u128,
+	) -> Option<T::AccountId> {
+		<TargetRules<T>>::iter_key_prefix(key).find_map(|rule_id| {
+			let rule = <Rules<T>>::get(rule_id)?;
+			if !rule.matches(input)
+				|| !Self::deployer_approved(&rule.target)
+				|| !Self::can_pay(&rule.sponsor, fee)
+			{
+				return None;
+			}
+
+			Self::try_use(rule_id, &rule, caller, fee)?;
+			Some(rule.sponsor)
+		})
+	}
+
+	/// Rules sponsoring contract creation stop applying once the factory
+	/// no longer approves the deployer.
+	fn deployer_approved(target: &SponsoredTarget) -> bool {
+		match target {
+			SponsoredTarget::Create { deployer, factory } => {
+				T::ApprovedDeployers::contains(factory, deployer)
+			}
+			SponsoredTarget::Contract { .. } | SponsoredTarget::Extrinsic { .. } => true,
+		}
+	}
+
+	/// The fee is withdrawn keeping the sponsor alive.
+	fn can_pay(sponsor: &T::AccountId, fee: u128) -> bool {
+		let fee: BalanceOf<T> = fee.saturated_into();
+		T::Currency::free_balance(sponsor)
+			.checked_sub(&fee)
+			.filter(|left| *left >= T::Currency::minimum_balance())
+			.map_or(false, |left| {
+				T::Currency::ensure_can_withdraw(sponsor, fee, WithdrawReasons::FEE, left).is_ok()
+			})
+	}
+
+	/// Checks the rate limit and the budget of the rule, and records the call if both allow it.
+	///
+	/// The fee is accounted by its upper bound, since the actual one is not known yet.
+	fn try_use(
+		rule_id: RuleId,
+		rule: &SponsorshipRuleOf<T>,
+		caller: &H160,
+		fee: u128,
+	) -> Option<()> {
+		let now = <frame_system::Pallet<T>>::block_number();
+
+		if !rule.rate_limit.is_zero() {
+			if let Some(last) = <LastSponsored<T>>::get(rule_id, caller) {
+				if now.saturating_sub(last) < rule.rate_limit {
+					return None;
+				}
+			}
+		}
+
+		if let Some(budget) = rule.budget {
+			let mut usage = <Budgets<T>>::get(rule_id);
+			if now.saturating_sub(usage.period_start) >= rule.period {
+				usage = BudgetUsage {
+					period_start: now,
+					spent: 0,
+				};
+			}
+			usage.spent = usage.spent.checked_add(fee)?;
+			if usage.spent > budget {
+				return None;
+			}
+			<Budgets<T>>::insert(rule_id, usage);
+		}
+
+		if !rule.rate_limit.is_zero() {
+			<LastSponsored<T>>::insert(rule_id, caller, now);
+		}
+		Some(())
+	}
+}
+
+impl<T: Config> SponsorshipHandler<T::CrossAccountId, CallContext> for Pallet<T> {
+	fn get_sponsor(who: &T::CrossAccountId, call: &CallContext) -> Option<T::CrossAccountId> {
+		let fee: u128 = call.max_fee.try_into().ok()?;
+		let key = TargetKey::Contract(call.contract_address);
+
+		Self::find_sponsor(key, &call.input, who.as_eth(), fee).map(T::CrossAccountId::from_sub)
+	}
+}
+
+impl<T: Config> SponsorshipHandler<T::CrossAccountId, CreateContext> for Pallet<T> {
+	fn get_sponsor(who: &T::CrossAccountId, call: &CreateContext) -> Option<T::CrossAccountId> {
+		let fee: u128 = call.max_fee.try_into().ok()?;
+		let key = TargetKey::Create(*who.as_eth());
+
+		Self::find_sponsor(key, &[], who.as_eth(), fee).map(T::CrossAccountId::from_sub)
+	}
+}
+
+impl<T: Config> SponsorshipHandler<T::AccountId, ExtrinsicContext> for Pallet<T> {
+	fn get_sponsor(who: &T::AccountId, call: &ExtrinsicContext) -> Option<T::AccountId> {
+		let key = TargetKey::Extrinsic(call.pallet_index, call.call_index);
+		// Rate limits are tracked by the mapped address, same as for the EVM callers
+		let who = T::CrossAccountId::from_sub(who.clone());
+
+		Self::find_sponsor(key, &call.args, who.as_eth(), call.fee)
+	}
+}
@@ -0,0 +1,112 @@
+use frame_support::{
+	storage_alias,
+	traits::{GetStorageVersion, StorageVersion},
+};
+
+use crate::*;
+
+/// Creates the given rules when the pallet is first deployed to a running chain,
+/// to keep sponsoring calls which used to be sponsored by a hard-coded handler.
+/// New chains get their rules from the genesis config.
+pub struct SeedRules<T, R>(PhantomData<(T, R)>);
+
+impl<T, R> OnRuntimeUpgrade for SeedRules<T, R>
+where
+	T: Config,
+	R: Get<Vec<SponsorshipRuleOf<T>>>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		if <Pallet<T>>::on_chain_storage_version() != 0 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let rules = R::get();
+		let count = rules.len() as u64;
+		for rule in rules {
+			if let Err(error) = <Pallet<T>>::insert_rule(rule) {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to seed sponsorship rule: {:?}",
+					error
+				);
+			}
+		}
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(1 + 2 * count, 1 + 4 * count)
+	}
+}
+
+pub(crate) mod v1 {
+	use super::*;
+
+	/// Rule, which could only sponsor calls to a contract.
+	#[derive(Encode, Decode)]
+	pub struct SponsorshipRule<AccountId, BlockNumber> {
+		pub contract: H160,
+		pub selector: [u8; 4],
+		pub selector_mask: [u8; 4],
+		pub sponsor: AccountId,
+		pub rate_limit: BlockNumber,
+		pub budget: Option<u128>,
+		pub period: BlockNumber,
+	}
+
+	#[storage_alias]
+	pub type ContractRules<T: Config> =
+		StorageDoubleMap<Pallet<T>, Blake2_128Concat, H160, Twox64Concat, RuleId, ()>;
+
+	#[storage_alias]
+	pub type ContractRuleCount<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, H160, u32, ValueQuery>;
+}
+
+/// Moves the rules of storage version 1, which only targeted contracts,
+/// to the [`SponsoredTarget`] layout and re-indexes them by [`TargetKey`].
+pub struct MigrateToTargets<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for MigrateToTargets<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if <Pallet<T>>::on_chain_storage_version() != 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut rules = 0u64;
+		<Rules<T>>::translate::<v1::SponsorshipRule<T::AccountId, BlockNumberFor<T>>, _>(
+			|_, rule| {
+				rules += 1;
+				Some(SponsorshipRule {
+					target: SponsoredTarget::Contract {
+						address: rule.contract,
+						selector: rule.selector,
+						selector_mask: rule.selector_mask,
+					},
+					sponsor: rule.sponsor,
+					rate_limit: rule.rate_limit,
+					budget: rule.budget,
+					period: rule.period,
+				})
+			},
+		);
+
+		let mut contracts = 0u64;
+		for (contract, rule_id, ()) in <v1::ContractRules<T>>::drain() {
+			<TargetRules<T>>::insert(TargetKey::Contract(contract), rule_id, ());
+		}
+		for (contract, count) in <v1::ContractRuleCount<T>>::drain() {
+			contracts += 1;
+			<TargetRuleCount<T>>::insert(TargetKey::Contract(contract), count);
+		}
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		log::info!(
+			target: LOG_TARGET,
+			"Migrated {} sponsorship rules of {} contracts",
+			rules,
+			contracts
+		);
+
+		// Every rule is indexed under its contract, and every contract has a count
+		T::DbWeight::get().reads_writes(1 + 2 * rules + contracts, 1 + 3 * rules + 2 * contracts)
+	}
+}
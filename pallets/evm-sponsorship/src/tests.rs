@@ -0,0 +1,388 @@
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use pallet_evm::account::CrossAccountId as _;
+use pallet_evm_transaction_payment::{CallContext, CreateContext, ExtrinsicContext};
+use sp_core::{H160, U256};
+use up_sponsorship::SponsorshipHandler;
+
+use crate::{mock::*, types::*, *};
+
+const SELECTOR: [u8; 4] = [0xAA, 0xBB, 0xCC, 0xDD];
+
+fn call_sponsor(input: &[u8], fee: u128) -> Option<AccountId> {
+	let context = CallContext {
+		contract_address: CONTRACT,
+		input: input.to_vec(),
+		max_fee: U256::from(fee),
+	};
+	<EvmSponsorship as SponsorshipHandler<CrossAccountId, CallContext>>::get_sponsor(
+		&eth(CALLER),
+		&context,
+	)
+	.map(|sponsor| sponsor.as_sub().clone())
+}
+
+fn create_sponsor(deployer: H160) -> Option<AccountId> {
+	let context = CreateContext {
+		max_fee: U256::from(1),
+	};
+	<EvmSponsorship as SponsorshipHandler<CrossAccountId, CreateContext>>::get_sponsor(
+		&eth(deployer),
+		&context,
+	)
+	.map(|sponsor| sponsor.as_sub().clone())
+}
+
+fn extrinsic_sponsor(args: &[u8]) -> Option<AccountId> {
+	let context = ExtrinsicContext {
+		pallet_index: 4,
+		call_index: 3,
+		args: args.to_vec(),
+		fee: 1,
+	};
+	<EvmSponsorship as SponsorshipHandler<AccountId, ExtrinsicContext>>::get_sponsor(
+		&OTHER, &context,
+	)
+}
+
+fn extrinsic_target(args_prefix: Vec<u8>) -> SponsoredTarget {
+	SponsoredTarget::Extrinsic {
+		pallet_index: 4,
+		call_index: 3,
+		args_prefix: args_prefix.try_into().unwrap(),
+	}
+}
+
+#[test]
+fn genesis_rules_are_created_without_deposit() {
+	new_test_ext(vec![rule(contract_target(SELECTOR, [0xFF; 4]))]).execute_with(|| {
+		assert_eq!(
+			EvmSponsorship::rule(0),
+			Some(rule(contract_target(SELECTOR, [0xFF; 4])))
+		);
+		assert_eq!(Balances::reserved_balance(SPONSOR), 0);
+		assert_eq!(call_sponsor(&SELECTOR, 1), Some(SPONSOR));
+	});
+}
+
+#[test]
+fn signed_rule_reserves_deposit_until_removed() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert_ok!(EvmSponsorship::create_rule(
+			RuntimeOrigin::signed(SPONSOR),
+			rule(contract_target(SELECTOR, [0xFF; 4]))
+		));
+		System::assert_last_event(
+			Event::RuleCreated {
+				rule_id: 0,
+				target: contract_target(SELECTOR, [0xFF; 4]),
+				sponsor: SPONSOR,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(SPONSOR), RULE_DEPOSIT);
+
+		assert_ok!(EvmSponsorship::remove_rule(
+			RuntimeOrigin::signed(SPONSOR),
+			0
+		));
+		System::assert_last_event(Event::RuleRemoved { rule_id: 0 }.into());
+		assert_eq!(Balances::reserved_balance(SPONSOR), 0);
+		assert_eq!(EvmSponsorship::rule(0), None);
+		assert_eq!(call_sponsor(&SELECTOR, 1), None);
+	});
+}
+
+#[test]
+fn signed_origin_sponsors_only_itself() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert_noop!(
+			EvmSponsorship::create_rule(
+				RuntimeOrigin::signed(OTHER),
+				rule(contract_target(SELECTOR, [0xFF; 4]))
+			),
+			<Error<Test>>::NotSponsor
+		);
+
+		assert_ok!(EvmSponsorship::create_rule(
+			RuntimeOrigin::root(),
+			rule(contract_target(SELECTOR, [0xFF; 4]))
+		));
+		assert_eq!(Balances::reserved_balance(SPONSOR), 0);
+		assert_noop!(
+			EvmSponsorship::remove_rule(RuntimeOrigin::signed(OTHER), 0),
+			<Error<Test>>::NotSponsor
+		);
+		assert_ok!(EvmSponsorship::remove_rule(RuntimeOrigin::root(), 0));
+	});
+}
+
+#[test]
+fn signed_origin_sponsors_only_controlled_targets() {
+	new_test_ext(vec![]).execute_with(|| {
+		let other = *CrossAccountId::from_sub(OTHER).as_eth();
+		assert_noop!(
+			EvmSponsorship::create_rule(
+				RuntimeOrigin::signed(OTHER),
+				SponsorshipRule {
+					sponsor: OTHER,
+					..rule(contract_target(SELECTOR, [0xFF; 4]))
+				}
+			),
+			<Error<Test>>::NotTargetOwner
+		);
+		assert_noop!(
+			EvmSponsorship::create_rule(
+				RuntimeOrigin::signed(OTHER),
+				SponsorshipRule {
+					sponsor: OTHER,
+					..rule(SponsoredTarget::Create {
+						deployer: DEPLOYER,
+						factory: FACTORY,
+					})
+				}
+			),
+			<Error<Test>>::NotTargetOwner
+		);
+
+		assert_ok!(EvmSponsorship::create_rule(
+			RuntimeOrigin::signed(OTHER),
+			SponsorshipRule {
+				sponsor: OTHER,
+				..rule(SponsoredTarget::Create {
+					deployer: other,
+					factory: FACTORY,
+				})
+			}
+		));
+		assert_eq!(Balances::reserved_balance(OTHER), RULE_DEPOSIT);
+	});
+}
+
+#[test]
+fn rules_per_target_are_limited() {
+	new_test_ext(vec![]).execute_with(|| {
+		for _ in 0..2 {
+			assert_ok!(EvmSponsorship::create_rule(
+				RuntimeOrigin::root(),
+				rule(contract_target(SELECTOR, [0xFF; 4]))
+			));
+		}
+		assert_noop!(
+			EvmSponsorship::create_rule(
+				RuntimeOrigin::root(),
+				rule(contract_target(SELECTOR, [0xFF; 4]))
+			),
+			<Error<Test>>::TooManyRules
+		);
+
+		// Removed rules free the slot
+		assert_ok!(EvmSponsorship::remove_rule(RuntimeOrigin::root(), 0));
+		assert_ok!(EvmSponsorship::create_rule(
+			RuntimeOrigin::root(),
+			rule(contract_target(SELECTOR, [0xFF; 4]))
+		));
+	});
+}
+
+#[test]
+fn budget_requires_period() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert_noop!(
+			EvmSponsorship::create_rule(
+				RuntimeOrigin::root(),
+				SponsorshipRule {
+					budget: Some(100),
+					..rule(contract_target(SELECTOR, [0xFF; 4]))
+				}
+			),
+			<Error<Test>>::ZeroPeriod
+		);
+	});
+}
+
+#[test]
+fn contract_calls_are_filtered_by_selector() {
+	new_test_ext(vec![rule(contract_target(
+		[0xAA, 0xBB, 0x00, 0x00],
+		[0xFF, 0xFF, 0x00, 0x00],
+	))])
+	.execute_with(|| {
+		assert_eq!(call_sponsor(&SELECTOR, 1), Some(SPONSOR));
+		assert_eq!(call_sponsor(&[0xAA, 0xBC, 0xCC, 0xDD], 1), None);
+		assert_eq!(call_sponsor(&[0xAA, 0xBB], 1), None);
+	});
+
+	new_test_ext(vec![rule(contract_target([0; 4], [0; 4]))]).execute_with(|| {
+		assert_eq!(call_sponsor(&[], 1), Some(SPONSOR));
+	});
+}
+
+#[test]
+fn rate_limit_is_per_caller() {
+	new_test_ext(vec![SponsorshipRule {
+		rate_limit: 2,
+		..rule(contract_target([0; 4], [0; 4]))
+	}])
+	.execute_with(|| {
+		assert_eq!(call_sponsor(&[], 1), Some(SPONSOR));
+		assert_eq!(call_sponsor(&[], 1), None);
+
+		System::set_block_number(2);
+		assert_eq!(call_sponsor(&[], 1), None);
+		System::set_block_number(3);
+		assert_eq!(call_sponsor(&[], 1), Some(SPONSOR));
+	});
+}
+
+#[test]
+fn budget_is_renewed_every_period() {
+	new_test_ext(vec![SponsorshipRule {
+		budget: Some(100),
+		period: 10,
+		..rule(contract_target([0; 4], [0; 4]))
+	}])
+	.execute_with(|| {
+		assert_eq!(call_sponsor(&[], 60), Some(SPONSOR));
+		assert_eq!(call_sponsor(&[], 60), None);
+		assert_eq!(call_sponsor(&[], 40), Some(SPONSOR));
+
+		System::set_block_number(11);
+		assert_eq!(call_sponsor(&[], 60), Some(SPONSOR));
+	});
+}
+
+#[test]
+fn sponsors_unable_to_pay_are_skipped() {
+	new_test_ext(vec![SponsorshipRule {
+		sponsor: UNFUNDED,
+		rate_limit: 10,
+		..rule(contract_target([0; 4], [0; 4]))
+	}])
+	.execute_with(|| {
+		// The caller pays, instead of the transaction being rejected
+		assert_eq!(call_sponsor(&[], 1), None);
+		assert!(!<LastSponsored<Test>>::contains_key(0, CALLER));
+
+		assert_ok!(EvmSponsorship::create_rule(
+			RuntimeOrigin::root(),
+			rule(contract_target([0; 4], [0; 4]))
+		));
+		assert_eq!(call_sponsor(&[], 1), Some(SPONSOR));
+		// The sponsor is kept alive
+		assert_eq!(call_sponsor(&[], INITIAL_BALANCE), None);
+	});
+}
+
+#[test]
+fn create_is_sponsored_for_deployers_approved_by_factory() {
+	new_test_ext(vec![
+		rule(SponsoredTarget::Create {
+			deployer: DEPLOYER,
+			factory: FACTORY,
+		}),
+		rule(SponsoredTarget::Create {
+			deployer: CALLER,
+			factory: FACTORY,
+		}),
+	])
+	.execute_with(|| {
+		assert_eq!(create_sponsor(DEPLOYER), Some(SPONSOR));
+		// The rule exists, but the factory doesn't approve the deployer
+		assert_eq!(create_sponsor(CALLER), None);
+		assert_eq!(create_sponsor(H160::repeat_byte(0x50)), None);
+	});
+}
+
+#[test]
+fn extrinsics_are_filtered_by_args_prefix() {
+	new_test_ext(vec![rule(extrinsic_target(vec![1, 2]))]).execute_with(|| {
+		assert_eq!(extrinsic_sponsor(&[1, 2, 3]), Some(SPONSOR));
+		assert_eq!(extrinsic_sponsor(&[1, 3, 3]), None);
+		assert_eq!(extrinsic_sponsor(&[1]), None);
+	});
+
+	new_test_ext(vec![rule(extrinsic_target(vec![]))]).execute_with(|| {
+		assert_eq!(extrinsic_sponsor(&[]), Some(SPONSOR));
+		assert_eq!(extrinsic_sponsor(&[5, 6]), Some(SPONSOR));
+	});
+}
+
+#[test]
+fn extrinsic_rules_are_created_only_by_manager() {
+	new_test_ext(vec![]).execute_with(|| {
+		assert_noop!(
+			EvmSponsorship::create_rule(
+				RuntimeOrigin::signed(SPONSOR),
+				rule(extrinsic_target(vec![]))
+			),
+			<Error<Test>>::NotTargetOwner
+		);
+		assert_ok!(EvmSponsorship::create_rule(
+			RuntimeOrigin::root(),
+			rule(extrinsic_target(vec![]))
+		));
+	});
+}
+
+#[test]
+fn rate_limits_of_removed_rules_are_cleared_on_idle() {
+	new_test_ext(vec![SponsorshipRule {
+		rate_limit: 10,
+		..rule(contract_target([0; 4], [0; 4]))
+	}])
+	.execute_with(|| {
+		assert_eq!(call_sponsor(&[], 1), Some(SPONSOR));
+		assert!(<LastSponsored<Test>>::contains_key(0, CALLER));
+
+		assert_ok!(EvmSponsorship::remove_rule(RuntimeOrigin::root(), 0));
+		assert!(<RemovedRules<Test>>::contains_key(0));
+
+		EvmSponsorship::on_idle(1, Weight::MAX);
+		assert!(!<LastSponsored<Test>>::contains_key(0, CALLER));
+		assert!(!<RemovedRules<Test>>::contains_key(0));
+	});
+}
+
+#[test]
+fn contract_rules_are_migrated_to_targets() {
+	new_test_ext(vec![]).execute_with(|| {
+		StorageVersion::new(1).put::<EvmSponsorship>();
+		let old = migration::v1::SponsorshipRule {
+			contract: CONTRACT,
+			selector: SELECTOR,
+			selector_mask: [0xFF; 4],
+			sponsor: SPONSOR,
+			rate_limit: 0u64,
+			budget: None,
+			period: 0u64,
+		};
+		unhashed::put(&<Rules<Test>>::hashed_key_for(7), &old);
+		<migration::v1::ContractRules<Test>>::insert(CONTRACT, 7, ());
+		<migration::v1::ContractRuleCount<Test>>::insert(CONTRACT, 1);
+
+		migration::MigrateToTargets::<Test>::on_runtime_upgrade();
+
+		assert_eq!(EvmSponsorship::on_chain_storage_version(), 2);
+		assert_eq!(
+			EvmSponsorship::rule(7),
+			Some(rule(contract_target(SELECTOR, [0xFF; 4])))
+		);
+		assert!(<TargetRules<Test>>::contains_key(
+			TargetKey::Contract(CONTRACT),
+			7
+		));
+		assert_eq!(
+			<TargetRuleCount<Test>>::get(TargetKey::Contract(CONTRACT)),
+			1
+		);
+		assert!(!<migration::v1::ContractRules<Test>>::contains_key(
+			CONTRACT, 7
+		));
+		assert_eq!(call_sponsor(&SELECTOR, 1), Some(SPONSOR));
+	});
+}
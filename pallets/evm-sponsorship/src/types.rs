@@ -0,0 +1,147 @@
+//! Types of the sponsorship rules.
+
+use frame_support::pallet_prelude::*;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+pub type RuleId = u32;
+
+/// Maximum length of [`SponsoredTarget::Extrinsic::args_prefix`].
+pub const MAX_ARGS_PREFIX_LEN: u32 = 128;
+
+pub type ArgsPrefix = BoundedVec<u8, ConstU32<MAX_ARGS_PREFIX_LEN>>;
+
+/// Transactions sponsored by a rule.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum SponsoredTarget {
+	/// Calls to the contract.
+	Contract {
+		/// Contract, calls to which are sponsored.
+		address: H160,
+		/// Selector of the sponsored method, compared only in bits set in `selector_mask`.
+		selector: [u8; 4],
+		/// Zero mask matches any call to the contract.
+		selector_mask: [u8; 4],
+	},
+	/// Contracts created by the deployer, while it is approved by the factory.
+	Create {
+		/// Account allowed to deploy contracts at the expense of the sponsor.
+		deployer: H160,
+		/// Contract, which approves the deployer, see `Config::ApprovedDeployers`.
+		factory: H160,
+	},
+	/// Substrate extrinsics.
+	Extrinsic {
+		/// Index of the pallet in the runtime.
+		pallet_index: u8,
+		/// Index of the call in the pallet.
+		call_index: u8,
+		/// Prefix of the SCALE-encoded call arguments.
+		/// Empty prefix matches any arguments, so the sponsor pays for the call
+		/// whatever the caller passes to it, e.g. any destination and amount of a transfer.
+		args_prefix: ArgsPrefix,
+	},
+}
+
+impl SponsoredTarget {
+	/// Key, under which the rules of the target are looked up.
+	pub fn key(&self) -> TargetKey {
+		match self {
+			Self::Contract { address, .. } => TargetKey::Contract(*address),
+			Self::Create { deployer, .. } => TargetKey::Create(*deployer),
+			Self::Extrinsic {
+				pallet_index,
+				call_index,
+				..
+			} => TargetKey::Extrinsic(*pallet_index, *call_index),
+		}
+	}
+}
+
+/// [`SponsoredTarget`] without the call filters.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum TargetKey {
+	/// Contract address.
+	Contract(H160),
+	/// Deployer address.
+	Create(H160),
+	/// Pallet and call indices.
+	Extrinsic(u8, u8),
+}
+
+/// Describes which transactions are sponsored and by whom.
+#[derive(
+	Clone,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	RuntimeDebug,
+	MaxEncodedLen,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub struct SponsorshipRule<AccountId, BlockNumber> {
+	/// Transactions, which are sponsored.
+	pub target: SponsoredTarget,
+	/// Account paying the fees.
+	pub sponsor: AccountId,
+	/// Minimum number of blocks between sponsored transactions of the same caller.
+	pub rate_limit: BlockNumber,
+	/// Maximum amount of fees paid by the sponsor during the `period`,
+	/// `None` means there is no limit.
+	pub budget: Option<u128>,
+	/// Length of the budget period in blocks.
+	pub period: BlockNumber,
+}
+
+impl<AccountId, BlockNumber> SponsorshipRule<AccountId, BlockNumber> {
+	/// Checks whether the call input matches the filter of the rule: the selector of
+	/// a contract call, or the argument prefix of an extrinsic, which is given without
+	/// the pallet and call indices.
+	pub fn matches(&self, input: &[u8]) -> bool {
+		match &self.target {
+			SponsoredTarget::Contract {
+				selector,
+				selector_mask,
+				..
+			} => {
+				if *selector_mask == [0; 4] {
+					return true;
+				}
+				if input.len() < 4 {
+					return false;
+				}
+
+				input[..4]
+					.iter()
+					.zip(selector)
+					.zip(selector_mask)
+					.all(|((input, selector), mask)| input & mask == selector & mask)
+			}
+			SponsoredTarget::Extrinsic { args_prefix, .. } => input.starts_with(args_prefix),
+			SponsoredTarget::Create { .. } => true,
+		}
+	}
+}
+
+/// Fees paid by the sponsor in the current budget period.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
+pub struct BudgetUsage<BlockNumber> {
+	/// Block, at which the current period has started.
+	pub period_start: BlockNumber,
+	/// Fees paid since `period_start`.
+	pub spent: u128,
+}
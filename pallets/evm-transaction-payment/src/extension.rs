@@ -0,0 +1,160 @@
+use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
+use pallet_transaction_payment::{ChargeTransactionPayment, OnChargeTransaction};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{TransactionValidity, TransactionValidityError},
+};
+
+use super::*;
+
+type BalanceOf<T> = <<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Drop-in replacement of [`ChargeTransactionPayment`], which charges the fee
+/// from the sponsor returned by [`Config::SubstrateSponsorshipHandler`], if there is one.
+///
+/// Has the same encoding and identifier as [`ChargeTransactionPayment`],
+/// so it doesn't change the extrinsic format.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeTransactionPaymentWithSponsor<T: pallet_transaction_payment::Config>(
+	#[codec(compact)] BalanceOf<T>,
+);
+
+impl<T: pallet_transaction_payment::Config> From<BalanceOf<T>>
+	for ChargeTransactionPaymentWithSponsor<T>
+{
+	fn from(tip: BalanceOf<T>) -> Self {
+		Self(tip)
+	}
+}
+
+impl<T: pallet_transaction_payment::Config> sp_std::fmt::Debug
+	for ChargeTransactionPaymentWithSponsor<T>
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeTransactionPaymentWithSponsor<{:?}>", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T> ChargeTransactionPaymentWithSponsor<T>
+where
+	T: Config + pallet_transaction_payment::Config,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	fn inner(&self) -> ChargeTransactionPayment<T> {
+		ChargeTransactionPayment::from(self.0)
+	}
+
+	/// Changes made by the handler are discarded when `is_check` is set,
+	/// since the transaction is only validated.
+	fn sponsor(
+		&self,
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+		info: &DispatchInfoOf<<T as frame_system::Config>::RuntimeCall>,
+		len: usize,
+		is_check: bool,
+	) -> Option<T::AccountId> {
+		// Calls are encoded as the pallet index, followed by the call index and the arguments
+		let encoded = call.encode();
+		let (&pallet_index, &call_index) = (encoded.first()?, encoded.get(1)?);
+		let fee = <pallet_transaction_payment::Pallet<T>>::compute_fee(len as u32, info, self.0);
+		let context = ExtrinsicContext {
+			pallet_index,
+			call_index,
+			args: encoded[2..].to_vec(),
+			fee: fee.unique_saturated_into(),
+		};
+
+		with_transaction(|| {
+			let result = T::SubstrateSponsorshipHandler::get_sponsor(who, &context);
+			if is_check {
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+			} else {
+				TransactionOutcome::Commit(Ok(result))
+			}
+		})
+		.ok()
+		.flatten()
+	}
+}
+
+impl<T> SignedExtension for ChargeTransactionPaymentWithSponsor<T>
+where
+	T: Config + pallet_transaction_payment::Config + Send + Sync,
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+	ChargeTransactionPayment<T>: SignedExtension<
+		AccountId = T::AccountId,
+		Call = <T as frame_system::Config>::RuntimeCall,
+		AdditionalSigned = (),
+	>,
+{
+	const IDENTIFIER: &'static str = <ChargeTransactionPayment<T> as SignedExtension>::IDENTIFIER;
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = <ChargeTransactionPayment<T> as SignedExtension>::Pre;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		// The caller pays, if the sponsor can't
+		let sponsored = self
+			.sponsor(who, call, info, len, true)
+			.and_then(|sponsor| self.inner().validate(&sponsor, call, info, len).ok());
+		sponsored.map_or_else(|| self.inner().validate(who, call, info, len), Ok)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		// The caller pays, if the sponsor can't,
+		// then the changes made by the handler are discarded as well
+		let sponsored = with_transaction(|| {
+			let pre = self
+				.sponsor(who, call, info, len, false)
+				.and_then(|sponsor| self.inner().pre_dispatch(&sponsor, call, info, len).ok());
+			match pre {
+				Some(pre) => TransactionOutcome::Commit(Ok::<_, DispatchError>(Some(pre))),
+				None => TransactionOutcome::Rollback(Ok(None)),
+			}
+		})
+		.ok()
+		.flatten();
+		// The fee is refunded to the account it was withdrawn from, it is recorded in `Pre`
+		sponsored.map_or_else(|| self.inner().pre_dispatch(who, call, info, len), Ok)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &sp_runtime::DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		<ChargeTransactionPayment<T> as SignedExtension>::post_dispatch(
+			pre, info, post_info, len, result,
+		)
+	}
+}
@@ -33,6 +33,14 @@ use sp_core::{H160, U256};
 use sp_runtime::{traits::UniqueSaturatedInto, DispatchError, TransactionOutcome};
 use up_sponsorship::SponsorshipHandler;
 
+mod extension;
+pub use extension::ChargeTransactionPaymentWithSponsor;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use sp_std::vec::Vec;
@@ -49,10 +57,33 @@ pub mod pallet {
 		pub max_fee: U256,
 	}
 
+	/// Contains contract creation data
+	pub struct CreateContext {
+		/// Max fee for transaction - gasLimit * gasPrice
+		pub max_fee: U256,
+	}
+
+	/// Contains substrate extrinsic data
+	pub struct ExtrinsicContext {
+		/// Index of the called pallet in the runtime
+		pub pallet_index: u8,
+		/// Index of the call in the pallet
+		pub call_index: u8,
+		/// SCALE-encoded call arguments, following the indices
+		pub args: Vec<u8>,
+		/// Fee for the extrinsic, including the tip
+		pub fee: u128,
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config + pallet_evm::Config {
 		/// Loosly-coupled handlers for evm call sponsoring
 		type EvmSponsorshipHandler: SponsorshipHandler<Self::CrossAccountId, CallContext>;
+		/// Loosly-coupled handlers for evm contract creation sponsoring
+		type EvmCreateSponsorshipHandler: SponsorshipHandler<Self::CrossAccountId, CreateContext>;
+		/// Loosly-coupled handlers for substrate extrinsic sponsoring,
+		/// used by [`ChargeTransactionPaymentWithSponsor`]
+		type SubstrateSponsorshipHandler: SponsorshipHandler<Self::AccountId, ExtrinsicContext>;
 	}
 
 	#[pallet::pallet]
@@ -74,6 +105,11 @@ fn who_pays_fee<T: Config>(
 			};
 			T::EvmSponsorshipHandler::get_sponsor(&origin_sub, &call_context)
 		}
+		WithdrawReason::Create => {
+			let origin_sub = T::CrossAccountId::from_eth(origin);
+			let create_context = CreateContext { max_fee };
+			T::EvmCreateSponsorshipHandler::get_sponsor(&origin_sub, &create_context)
+		}
 		_ => None,
 	}
 }
@@ -96,6 +132,15 @@ fn get_sponsor<T: Config>(
 
 	let max_fee = max_fee_per_gas.saturating_mul(gas_limit.into());
 
+	get_sponsor_for_fee::<T>(source, max_fee, reason, is_check)
+}
+
+fn get_sponsor_for_fee<T: Config>(
+	source: H160,
+	max_fee: U256,
+	reason: &WithdrawReason,
+	is_check: bool,
+) -> Option<T::CrossAccountId> {
 	with_transaction(|| {
 		let result = who_pays_fee::<T>(source, max_fee, reason);
 		if is_check {
@@ -108,6 +153,45 @@ fn get_sponsor<T: Config>(
 	.flatten()
 }
 
+fn withdraw_sponsor<T: Config>(
+	source: H160,
+	reason: &WithdrawReason,
+	fee: U256,
+	is_check: bool,
+) -> Option<T::CrossAccountId> {
+	match *reason {
+		WithdrawReason::Call {
+			max_fee_per_gas,
+			gas_limit,
+			is_transactional,
+			is_check: is_call_check,
+			..
+		} => get_sponsor::<T>(
+			source,
+			max_fee_per_gas,
+			gas_limit,
+			reason,
+			is_transactional,
+			is_check || is_call_check,
+		),
+		// Gas parameters are not passed for creation, but the fee is already the upper bound
+		WithdrawReason::Create => get_sponsor_for_fee::<T>(source, fee, reason, is_check),
+		_ => None,
+	}
+}
+
+/// Checks whether the fee withdrawn for `reason` would be paid by a sponsor.
+///
+/// Nothing is recorded by the sponsorship handlers, so the sponsor is still available
+/// to [`WrappedEVMCurrencyAdapter`] afterwards.
+pub fn is_sponsored<T: Config>(
+	who: &T::CrossAccountId,
+	reason: &WithdrawReason,
+	fee: U256,
+) -> bool {
+	withdraw_sponsor::<T>(*who.as_eth(), reason, fee, true).is_some()
+}
+
 /// Set transaction sponsor if available and enough balance.
 pub struct TransactionValidity<T>(PhantomData<T>);
 impl<T: Config> OnCheckEvmTransaction<T> for TransactionValidity<T> {
@@ -188,23 +272,7 @@ where
 		reason: WithdrawReason,
 		fee: U256,
 	) -> Result<Self::LiquidityInfo, pallet_evm::Error<T>> {
-		let sponsor = match reason {
-			WithdrawReason::Call {
-				max_fee_per_gas,
-				gas_limit,
-				is_transactional,
-				is_check,
-				..
-			} => get_sponsor::<T>(
-				*who.as_eth(),
-				max_fee_per_gas,
-				gas_limit,
-				&reason,
-				is_transactional,
-				is_check,
-			),
-			_ => None,
-		};
+		let sponsor = withdraw_sponsor::<T>(*who.as_eth(), &reason, fee, false);
 
 		let who = sponsor.as_ref().unwrap_or(who);
 		<pallet_evm::EVMCurrencyAdapter<C, Treasury> as OnChargeEVMTransaction<T>>::withdraw_fee(
@@ -0,0 +1,215 @@
+use frame_support::{
+	construct_runtime, parameter_types,
+	storage::unhashed,
+	traits::{ConstU128, ConstU32, ConstU64, ConstU8, Currency},
+	weights::{IdentityFee, Weight},
+};
+use pallet_evm::{
+	account::{BasicCrossAccountId, CrossAccountId as _},
+	EnsureAddressNever, HashedAddressMapping, SubstrateBlockHashMapping,
+};
+use pallet_transaction_payment::CurrencyAdapter;
+use sp_core::{H160, U256};
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	AccountId32, BuildStorage,
+};
+
+use crate::{self as pallet_evm_transaction_payment, *};
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+pub type CrossAccountId = BasicCrossAccountId<Test>;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Timestamp: pallet_timestamp,
+		TransactionPayment: pallet_transaction_payment,
+		EVM: pallet_evm,
+		EvmTransactionPayment: pallet_evm_transaction_payment,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = sp_core::H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+	type FreezeIdentifier = ();
+	type MaxHolds = ();
+	type MaxFreezes = ();
+}
+
+impl pallet_timestamp::Config for Test {
+	type Moment = u64;
+	type OnTimestampSet = ();
+	type MinimumPeriod = ConstU64<1>;
+	type WeightInfo = ();
+}
+
+impl pallet_transaction_payment::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OperationalFeeMultiplier = ConstU8<5>;
+	type WeightToFee = IdentityFee<Balance>;
+	type LengthToFee = IdentityFee<Balance>;
+	type FeeMultiplierUpdate = ();
+}
+
+parameter_types! {
+	pub BlockGasLimit: U256 = U256::from(u32::MAX);
+	pub const WeightPerGas: Weight = Weight::from_parts(20_000, 0);
+}
+
+impl pallet_evm::Config for Test {
+	type CrossAccountId = CrossAccountId;
+	type AddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BackwardsAddressMapping = HashedAddressMapping<BlakeTwo256>;
+	type BlockGasLimit = BlockGasLimit;
+	type FeeCalculator = ();
+	type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+	type WeightPerGas = WeightPerGas;
+	type BlockHashMapping = SubstrateBlockHashMapping<Self>;
+	type CallOrigin = EnsureAddressNever<AccountId>;
+	type WithdrawOrigin = EnsureAddressNever<AccountId>;
+	type PrecompilesType = ();
+	type PrecompilesValue = ();
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type OnMethodCall = ();
+	type OnCreate = ();
+	type ChainId = ConstU64<8888>;
+	type Runner = pallet_evm::runner::stack::Runner<Self>;
+	type OnChargeTransaction = ();
+	type FindAuthor = ();
+	type Timestamp = Timestamp;
+	type WeightInfo = ();
+	type GasLimitPovSizeRatio = ConstU64<4>;
+	type OnCheckEvmTransaction = ();
+}
+
+pub const CONTRACT: H160 = H160([0x10; 20]);
+pub const DEPLOYER: H160 = H160([0x20; 20]);
+pub const SPONSOR: H160 = H160([0x30; 20]);
+pub const CALLER: H160 = H160([0x40; 20]);
+pub const SUBSTRATE_SPONSOR: AccountId = AccountId32::new([1; 32]);
+pub const SUBSTRATE_CALLER: AccountId = AccountId32::new([2; 32]);
+pub const INITIAL_BALANCE: Balance = 1_000_000_000_000;
+
+const SPONSORED_KEY: &[u8] = b":sponsored:";
+
+/// Number of fees the sponsors have agreed to pay,
+/// it is kept in storage to check that the lookups made for validation are discarded.
+pub fn sponsored_count() -> u32 {
+	unhashed::get_or_default(SPONSORED_KEY)
+}
+
+fn note_sponsored() {
+	unhashed::put(SPONSORED_KEY, &(sponsored_count() + 1));
+}
+
+/// [`SPONSOR`] pays for the calls to [`CONTRACT`] and for the contracts created by [`DEPLOYER`],
+/// [`SUBSTRATE_SPONSOR`] pays for the calls of the `System` pallet.
+pub struct Sponsors;
+
+impl SponsorshipHandler<CrossAccountId, CallContext> for Sponsors {
+	fn get_sponsor(_who: &CrossAccountId, call: &CallContext) -> Option<CrossAccountId> {
+		(call.contract_address == CONTRACT).then(|| {
+			note_sponsored();
+			eth(SPONSOR)
+		})
+	}
+}
+
+impl SponsorshipHandler<CrossAccountId, CreateContext> for Sponsors {
+	fn get_sponsor(who: &CrossAccountId, _call: &CreateContext) -> Option<CrossAccountId> {
+		(*who.as_eth() == DEPLOYER).then(|| {
+			note_sponsored();
+			eth(SPONSOR)
+		})
+	}
+}
+
+impl SponsorshipHandler<AccountId, ExtrinsicContext> for Sponsors {
+	fn get_sponsor(_who: &AccountId, call: &ExtrinsicContext) -> Option<AccountId> {
+		(call.pallet_index == 0).then(|| {
+			note_sponsored();
+			SUBSTRATE_SPONSOR
+		})
+	}
+}
+
+impl pallet_evm_transaction_payment::Config for Test {
+	type EvmSponsorshipHandler = Sponsors;
+	type EvmCreateSponsorshipHandler = Sponsors;
+	type SubstrateSponsorshipHandler = Sponsors;
+}
+
+pub fn eth(address: H160) -> CrossAccountId {
+	CrossAccountId::from_eth(address)
+}
+
+/// Free balance of the `address`'s substrate mirror.
+pub fn balance(address: H160) -> Balance {
+	Balances::free_balance(eth(address).as_sub())
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let storage = RuntimeGenesisConfig {
+		balances: pallet_balances::GenesisConfig {
+			balances: vec![
+				(SUBSTRATE_SPONSOR, INITIAL_BALANCE),
+				(SUBSTRATE_CALLER, INITIAL_BALANCE),
+			],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		for address in [SPONSOR, CALLER, DEPLOYER] {
+			Balances::make_free_balance_be(eth(address).as_sub(), INITIAL_BALANCE);
+		}
+	});
+	ext
+}
@@ -0,0 +1,234 @@
+use frame_support::{
+	assert_ok,
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	weights::Weight,
+};
+use pallet_evm::account::CrossAccountId as _;
+use sp_runtime::traits::SignedExtension;
+
+use crate::{mock::*, *};
+
+type FeeAdapter = WrappedEVMCurrencyAdapter<Balances, ()>;
+
+fn call_reason(target: H160) -> WithdrawReason {
+	WithdrawReason::Call {
+		target,
+		input: vec![],
+		max_fee_per_gas: Some(U256::from(1)),
+		gas_limit: 100,
+		is_transactional: true,
+		is_check: false,
+	}
+}
+
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![1] })
+}
+
+#[test]
+fn sponsorship_checks_are_discarded() {
+	new_test_ext().execute_with(|| {
+		assert!(is_sponsored::<Test>(
+			&eth(CALLER),
+			&call_reason(CONTRACT),
+			100.into()
+		));
+		assert!(!is_sponsored::<Test>(
+			&eth(CALLER),
+			&call_reason(CALLER),
+			100.into()
+		));
+		assert!(is_sponsored::<Test>(
+			&eth(DEPLOYER),
+			&WithdrawReason::Create,
+			100.into()
+		));
+		assert!(!is_sponsored::<Test>(
+			&eth(CALLER),
+			&WithdrawReason::Create,
+			100.into()
+		));
+		assert_eq!(sponsored_count(), 0);
+	});
+}
+
+#[test]
+fn sponsor_pays_and_is_refunded_for_calls() {
+	new_test_ext().execute_with(|| {
+		let withdrawn = <FeeAdapter as OnChargeEVMTransaction<Test>>::withdraw_fee(
+			&eth(CALLER),
+			call_reason(CONTRACT),
+			100.into(),
+		)
+		.unwrap();
+		assert_eq!(sponsored_count(), 1);
+		assert_eq!(balance(SPONSOR), INITIAL_BALANCE - 100);
+		assert_eq!(balance(CALLER), INITIAL_BALANCE);
+
+		<FeeAdapter as OnChargeEVMTransaction<Test>>::correct_and_deposit_fee(
+			&eth(CALLER),
+			60.into(),
+			60.into(),
+			withdrawn,
+		);
+		assert_eq!(balance(SPONSOR), INITIAL_BALANCE - 60);
+		assert_eq!(balance(CALLER), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn caller_pays_for_not_sponsored_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<FeeAdapter as OnChargeEVMTransaction<Test>>::withdraw_fee(
+			&eth(CALLER),
+			call_reason(CALLER),
+			100.into(),
+		));
+		assert_eq!(balance(SPONSOR), INITIAL_BALANCE);
+		assert_eq!(balance(CALLER), INITIAL_BALANCE - 100);
+
+		// The gas price is required for transactional calls
+		assert_ok!(<FeeAdapter as OnChargeEVMTransaction<Test>>::withdraw_fee(
+			&eth(CALLER),
+			WithdrawReason::Call {
+				target: CONTRACT,
+				input: vec![],
+				max_fee_per_gas: None,
+				gas_limit: 100,
+				is_transactional: true,
+				is_check: false,
+			},
+			100.into(),
+		));
+		assert_eq!(balance(CALLER), INITIAL_BALANCE - 200);
+		assert_eq!(sponsored_count(), 0);
+	});
+}
+
+#[test]
+fn sponsor_pays_for_created_contracts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(<FeeAdapter as OnChargeEVMTransaction<Test>>::withdraw_fee(
+			&eth(DEPLOYER),
+			WithdrawReason::Create,
+			100.into(),
+		));
+		assert_eq!(balance(SPONSOR), INITIAL_BALANCE - 100);
+		assert_eq!(balance(DEPLOYER), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn extrinsic_fee_is_charged_from_sponsor() {
+	new_test_ext().execute_with(|| {
+		let info = DispatchInfo {
+			weight: Weight::from_parts(10, 0),
+			..Default::default()
+		};
+		let len = 10;
+		let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+
+		let pre = ChargeTransactionPaymentWithSponsor::<Test>::from(0)
+			.pre_dispatch(&SUBSTRATE_CALLER, &remark(), &info, len)
+			.unwrap();
+		assert_eq!(sponsored_count(), 1);
+		assert_eq!(
+			Balances::free_balance(SUBSTRATE_SPONSOR),
+			INITIAL_BALANCE - fee
+		);
+		assert_eq!(Balances::free_balance(SUBSTRATE_CALLER), INITIAL_BALANCE);
+
+		assert_ok!(ChargeTransactionPaymentWithSponsor::<Test>::post_dispatch(
+			Some(pre),
+			&info,
+			&PostDispatchInfo::default(),
+			len,
+			&Ok(())
+		));
+		assert_eq!(Balances::free_balance(SUBSTRATE_CALLER), INITIAL_BALANCE);
+	});
+
+	new_test_ext().execute_with(|| {
+		let info = DispatchInfo {
+			weight: Weight::from_parts(10, 0),
+			..Default::default()
+		};
+		assert_ok!(
+			ChargeTransactionPaymentWithSponsor::<Test>::from(0).validate(
+				&SUBSTRATE_CALLER,
+				&remark(),
+				&info,
+				10
+			)
+		);
+		// The sponsor is asked, but nothing it records is kept
+		assert_eq!(sponsored_count(), 0);
+		assert_eq!(Balances::free_balance(SUBSTRATE_CALLER), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn extrinsic_fee_is_charged_from_caller_without_sponsor() {
+	new_test_ext().execute_with(|| {
+		let info = DispatchInfo {
+			weight: Weight::from_parts(10, 0),
+			..Default::default()
+		};
+		let len = 10;
+		let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+		let call = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: SUBSTRATE_SPONSOR,
+			value: 1,
+		});
+
+		assert_ok!(
+			ChargeTransactionPaymentWithSponsor::<Test>::from(0).pre_dispatch(
+				&SUBSTRATE_CALLER,
+				&call,
+				&info,
+				len
+			)
+		);
+		assert_eq!(
+			Balances::free_balance(SUBSTRATE_CALLER),
+			INITIAL_BALANCE - fee
+		);
+		assert_eq!(Balances::free_balance(SUBSTRATE_SPONSOR), INITIAL_BALANCE);
+	});
+}
+
+#[test]
+fn extrinsic_fee_is_charged_from_caller_if_sponsor_can_not_pay() {
+	new_test_ext().execute_with(|| {
+		let info = DispatchInfo {
+			weight: Weight::from_parts(10, 0),
+			..Default::default()
+		};
+		let len = 10;
+		let fee = TransactionPayment::compute_fee(len as u32, &info, 0);
+		Balances::make_free_balance_be(&SUBSTRATE_SPONSOR, 0);
+
+		assert_ok!(
+			ChargeTransactionPaymentWithSponsor::<Test>::from(0).validate(
+				&SUBSTRATE_CALLER,
+				&remark(),
+				&info,
+				len
+			)
+		);
+		assert_ok!(
+			ChargeTransactionPaymentWithSponsor::<Test>::from(0).pre_dispatch(
+				&SUBSTRATE_CALLER,
+				&remark(),
+				&info,
+				len
+			)
+		);
+		// The sponsorship is discarded along with the failed withdrawal
+		assert_eq!(sponsored_count(), 0);
+		assert_eq!(
+			Balances::free_balance(SUBSTRATE_CALLER),
+			INITIAL_BALANCE - fee
+		);
+	});
+}
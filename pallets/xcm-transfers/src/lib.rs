@@ -0,0 +1,134 @@
+#![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*};
+use frame_system::pallet_prelude::BlockNumberFor;
+pub use pallet::*;
+use pallet_chain_registry::{ChainId, Destination, TransferMode};
+use sp_core::H160;
+use sp_runtime::traits::TryConvert;
+use sp_std::{boxed::Box, marker::PhantomData, prelude::*};
+use xcm::latest::{Junction, Junctions, MultiLocation, QueryId, Response, XcmHash};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod transfer;
+
+pub(crate) const LOG_TARGET: &str = "runtime::xcm-transfers";
+
+/// Outcome of a cross-chain transfer, reported by the destination chain.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum TransferStatus {
+	/// The message was sent, but the outcome is not reported yet.
+	Pending,
+	/// The message was executed on the destination.
+	Delivered,
+	/// The destination didn't accept the assets.
+	Failed,
+	/// The assets were accepted, but the execution failed, so they were trapped on the destination.
+	Trapped,
+}
+
+impl TransferStatus {
+	fn from_response(response: &Response) -> Option<Self> {
+		match response {
+			Response::ExecutionResult(None) => Some(Self::Delivered),
+			// The first instruction puts the assets into the holding
+			Response::ExecutionResult(Some((0, _))) => Some(Self::Failed),
+			Response::ExecutionResult(Some(_)) => Some(Self::Trapped),
+			_ => None,
+		}
+	}
+}
+
+/// Transfer, whose message is being sent, see [`transfer::TrackTransfers`].
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct OutgoingTransfer {
+	pub destination: MultiLocation,
+	/// Query, which the destination reports the outcome to.
+	pub query_id: QueryId,
+	/// Weight of the response handler.
+	pub max_weight: Weight,
+	/// Id of the sent message, set once it is delivered to the router.
+	pub message_id: Option<XcmHash>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The destination reported the outcome of a cross-chain transfer.
+		TransferExecuted {
+			message_id: XcmHash,
+			status: TransferStatus,
+		},
+	}
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_xcm::Config + pallet_bridge_flows::Config
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Assets, which may be sent by [`transfer::transfer`].
+		type TransferableAssets: transfer::TransferableAssets;
+
+		/// For how long the outcome of a cross-chain transfer is awaited.
+		/// The status of the transfer is kept until then, see [`TransferExpiries`].
+		#[pallet::constant]
+		type TransferResponseTimeout: Get<BlockNumberFor<Self>>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	/// Transfer, whose message is being sent right now.
+	pub(super) type Outgoing<T: Config> = StorageValue<_, OutgoingTransfer>;
+
+	#[pallet::storage]
+	/// Statuses of cross-chain transfers by the ids of their messages.
+	pub(super) type Transfers<T: Config> = StorageMap<_, Identity, XcmHash, TransferStatus>;
+
+	#[pallet::storage]
+	/// Destinations and messages of cross-chain transfers, whose outcome is awaited.
+	pub(super) type TransferQueries<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, (MultiLocation, XcmHash)>;
+
+	#[pallet::storage]
+	/// Number of the queries made for cross-chain transfers,
+	/// see [`transfer::TRANSFER_QUERY_IDS`].
+	pub(super) type TransferQueryCount<T: Config> = StorageValue<_, QueryId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Cross-chain transfers by the blocks, at which their responses time out.
+	/// The statuses and the queries of the transfers are removed at these blocks.
+	pub(super) type TransferExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, QueryId, XcmHash>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// Every transfer is expired once, the removal is paid by the transfer itself
+			let mut expired = 0;
+			for (query_id, message_id) in <TransferExpiries<T>>::drain_prefix(now) {
+				<TransferQueries<T>>::remove(query_id);
+				<Transfers<T>>::remove(message_id);
+				expired += 1;
+			}
+			T::DbWeight::get().reads_writes(expired + 1, expired * 3)
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn transfer_status(message_id: &XcmHash) -> Option<TransferStatus> {
+		<Transfers<T>>::get(message_id)
+	}
+}
@@ -0,0 +1,117 @@
+use frame_support::traits::Hooks;
+use xcm::latest::{
+	Error as XcmError, Instruction, MultiAssetFilter::Wild, MultiAssets, QueryResponseInfo,
+	SendXcm, WeightLimit, WildMultiAsset::All, Xcm, XcmContext,
+};
+use xcm_executor::traits::OnResponse;
+
+use crate::{mock::*, *};
+
+#[test]
+fn transfer_outcome_is_reported_after_clearing_origin() {
+	new_test_ext().execute_with(|| {
+		let query_id = transfer::TRANSFER_QUERY_IDS;
+		<Outgoing<Test>>::put(OutgoingTransfer {
+			destination: para(2000),
+			query_id,
+			max_weight: Weight::zero(),
+			message_id: None,
+		});
+		let buy_execution = Instruction::BuyExecution {
+			fees: (MultiLocation::here(), 1u128).into(),
+			weight_limit: WeightLimit::Unlimited,
+		};
+		let deposit = Instruction::DepositAsset {
+			assets: Wild(All),
+			beneficiary: MultiLocation::here(),
+		};
+		let mut message = Some(Xcm(vec![
+			Instruction::ReceiveTeleportedAsset(MultiAssets::new()),
+			Instruction::ClearOrigin,
+			buy_execution.clone(),
+			deposit.clone(),
+		]));
+
+		// The message is not sent by the test router, but it is modified anyway
+		assert!(transfer::TrackTransfers::<Test, ()>::validate(
+			&mut Some(para(2000)),
+			&mut message
+		)
+		.is_err());
+		let report = QueryResponseInfo {
+			destination: UniversalLocation::get().invert_target(&para(2000)).unwrap(),
+			query_id,
+			max_weight: Weight::zero(),
+		};
+		assert_eq!(
+			message.unwrap().0,
+			vec![
+				Instruction::ReceiveTeleportedAsset(MultiAssets::new()),
+				Instruction::ClearOrigin,
+				buy_execution,
+				Instruction::SetAppendix(Xcm(vec![Instruction::ReportError(report)])),
+				deposit,
+			]
+		);
+	});
+}
+
+#[test]
+fn transfer_outcome_is_recorded_until_expiry() {
+	type ResponseHandler = transfer::TransferResponses<Test, ()>;
+
+	new_test_ext().execute_with(|| {
+		let message_id = [7; 32];
+		let query_id = transfer::TRANSFER_QUERY_IDS;
+		<Transfers<Test>>::insert(message_id, TransferStatus::Pending);
+		<TransferQueries<Test>>::insert(query_id, (para(2000), message_id));
+		<TransferExpiries<Test>>::insert(1 + RESPONSE_TIMEOUT, query_id, message_id);
+
+		// The origin is cleared before the outcome is reported, so there is no querier
+		assert!(ResponseHandler::expecting_response(
+			&para(2000),
+			query_id,
+			None
+		));
+		assert!(!ResponseHandler::expecting_response(
+			&para(2001),
+			query_id,
+			None
+		));
+		assert!(!ResponseHandler::expecting_response(&para(2000), 0, None));
+
+		let context = XcmContext {
+			origin: Some(para(2000)),
+			message_id: [0; 32],
+			topic: None,
+		};
+		ResponseHandler::on_response(
+			&para(2000),
+			query_id,
+			None,
+			Response::ExecutionResult(Some((1, XcmError::Unimplemented))),
+			Weight::MAX,
+			&context,
+		);
+		assert_eq!(
+			XcmTransfers::transfer_status(&message_id),
+			Some(TransferStatus::Trapped)
+		);
+		System::assert_last_event(
+			Event::TransferExecuted {
+				message_id,
+				status: TransferStatus::Trapped,
+			}
+			.into(),
+		);
+		// The outcome is reported once
+		assert!(!ResponseHandler::expecting_response(
+			&para(2000),
+			query_id,
+			None
+		));
+
+		XcmTransfers::on_initialize(1 + RESPONSE_TIMEOUT);
+		assert_eq!(XcmTransfers::transfer_status(&message_id), None);
+	});
+}
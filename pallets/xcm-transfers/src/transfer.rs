@@ -0,0 +1,374 @@
+//! Cross-chain transfers initiated by the EVM precompiles,
+//! shared by `pallet-balances-adapter` and `pallet-evm-assets`.
+
+use pallet_bridge_flows::FlowDirection;
+use pallet_evm_coder_substrate::{dispatch_to_evm, execution::Result};
+use pallet_xcm::Pallet as PalletXcm;
+use sp_core::H256;
+use sp_runtime::Saturating;
+use xcm::latest::{
+	AssetId, Fungibility, Instruction, MultiAsset, MultiAssets, NetworkId, QueryResponseInfo,
+	SendError, SendResult, SendXcm, WeightLimit, Xcm, XcmContext,
+};
+use xcm_executor::traits::OnResponse;
+
+use crate::*;
+
+/// Receiver of a cross-chain transfer on the destination chain.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum Beneficiary {
+	/// Ethereum-like account.
+	AccountKey20(H160),
+	/// Substrate account, e.g. sr25519.
+	AccountId32(H256),
+}
+
+impl Beneficiary {
+	/// Returns the account as `bytes32`, addresses are left-padded with zeros.
+	pub fn to_bytes32(&self) -> H256 {
+		match self {
+			Self::AccountKey20(key) => (*key).into(),
+			Self::AccountId32(id) => *id,
+		}
+	}
+
+	fn into_junction(self, network: NetworkId) -> Junction {
+		match self {
+			Self::AccountKey20(key) => Junction::AccountKey20 {
+				network: Some(network),
+				key: key.into(),
+			},
+			Self::AccountId32(id) => Junction::AccountId32 {
+				network: Some(network),
+				id: id.into(),
+			},
+		}
+	}
+}
+
+/// Assets, which may be sent by [`transfer`], identified by the addresses of their contracts.
+pub trait TransferableAssets {
+	/// Checks that `sender` may send `amount` of the asset to other chains.
+	///
+	/// Returns `None` if the asset is not handled by the implementation.
+	fn ensure_transferable(asset: &H160, sender: &H160, amount: u128) -> Option<DispatchResult>;
+}
+
+impl<A: TransferableAssets, B: TransferableAssets> TransferableAssets for (A, B) {
+	fn ensure_transferable(asset: &H160, sender: &H160, amount: u128) -> Option<DispatchResult> {
+		A::ensure_transferable(asset, sender, amount)
+			.or_else(|| B::ensure_transferable(asset, sender, amount))
+	}
+}
+
+/// Proof size of the weight limits passed to the precompiles, which only set the execution time.
+pub const DEFAULT_PROOF_SIZE: u64 = 256 * 1024;
+
+/// Ids of the queries made for the transfers are counted from here,
+/// so they don't collide with the queries of `pallet_xcm`, which are counted from zero.
+pub const TRANSFER_QUERY_IDS: QueryId = 1 << 63;
+
+/// Converts the weight limit passed to the precompiles: the maximum execution time
+/// on the destination, where `u64::MAX` means no limit.
+pub fn weight_limit(ref_time: u64) -> WeightLimit {
+	if ref_time == u64::MAX {
+		WeightLimit::Unlimited
+	} else {
+		WeightLimit::Limited(Weight::from_parts(ref_time, DEFAULT_PROOF_SIZE))
+	}
+}
+
+/// Transfers the assets, identified by the addresses of their contracts, from the `sender`
+/// to the `beneficiary` on the chain, either by a teleport or by a reserve transfer.
+///
+/// The destination of every asset is resolved by the `ChainLocator`, and all of the assets
+/// must be moved in the same way. The destination must be one of the locations accepted
+/// by [`pallet_chain_registry::is_supported_location`]. The amounts are counted against the outbound flow limits
+/// of the assets, kept by `pallet_bridge_flows`.
+///
+/// Execution on the destination is paid with the `fee_asset`, which must be one of the assets,
+/// and no more than `weight_limit` is bought.
+///
+/// Returns the id of the sent message, under which the outcome reported by the destination
+/// is tracked until `TransferResponseTimeout` passes. The message is only tracked when sent
+/// through [`TrackTransfers`].
+pub fn transfer<T, ChainLocator>(
+	origin: <T as frame_system::Config>::RuntimeOrigin,
+	sender: H160,
+	chain_id: ChainId,
+	assets: &[(H160, u128)],
+	fee_asset: H160,
+	weight_limit: WeightLimit,
+	beneficiary: Beneficiary,
+) -> Result<XcmHash>
+where
+	T: Config + pallet_evm_coder_substrate::Config,
+	ChainLocator: TryConvert<(ChainId, H160), Destination>,
+{
+	let relay_network = T::UniversalLocation::get()
+		.global_consensus()
+		.map_err(|_| "unable to get global consensus")?;
+
+	let mut destination = None;
+	for (index, &(asset, amount)) in assets.iter().enumerate() {
+		ensure!(
+			!assets[..index].iter().any(|(other, _)| *other == asset),
+			"duplicate asset"
+		);
+		T::TransferableAssets::ensure_transferable(&asset, &sender, amount)
+			.ok_or("unknown asset")?
+			.map_err(dispatch_to_evm::<T>)?;
+		let asset_destination =
+			ChainLocator::try_convert((chain_id, asset)).map_err(|_| "chain not found")?;
+		ensure!(
+			destination.map_or(true, |destination| destination == asset_destination),
+			"assets are transferred in different modes"
+		);
+		destination = Some(asset_destination);
+	}
+	let Destination {
+		location: destination,
+		mode,
+		..
+	} = destination.ok_or("no assets")?;
+
+	// Determining the asset location relative to the relay.
+	// For relay - 0, for parachains - 1.
+	// Correctness is ensured by the correct configuration of the `ChainLocator`.
+	let parents = match (destination.parent_count(), destination.interior()) {
+		(1, Junctions::Here) | (1, Junctions::X1(Junction::Parachain(_))) => Ok(1),
+		(0, Junctions::X1(Junction::Parachain(_))) => Ok(0),
+		_ => Err("unsupported location pattern"),
+	}?;
+
+	for &(asset, amount) in assets {
+		<pallet_bridge_flows::Pallet<T>>::note_flow(
+			asset,
+			Some(chain_id),
+			FlowDirection::Outbound,
+			amount,
+		)
+		.map_err(dispatch_to_evm::<T>)?;
+	}
+
+	let asset_id = |asset: H160| -> AssetId {
+		MultiLocation::new(
+			parents,
+			Junction::AccountKey20 {
+				network: Some(relay_network),
+				key: asset.into(),
+			},
+		)
+		.into()
+	};
+	let assets: MultiAssets = assets
+		.iter()
+		.map(|&(asset, amount)| MultiAsset {
+			id: asset_id(asset),
+			fun: Fungibility::Fungible(amount),
+		})
+		.collect::<Vec<_>>()
+		.into();
+	// The assets are sorted, so the fee asset is looked up after they are collected
+	let fee_asset_item = assets
+		.inner()
+		.iter()
+		.position(|asset| asset.id == asset_id(fee_asset))
+		.ok_or("fee asset is not transferred")? as u32;
+
+	let beneficiary = MultiLocation::new(0, beneficiary.into_junction(relay_network));
+
+	// The destination reports the outcome to this query, see `TrackTransfers`
+	// and `TransferResponses`
+	let timeout =
+		<frame_system::Pallet<T>>::block_number().saturating_add(T::TransferResponseTimeout::get());
+	let query_id = <TransferQueryCount<T>>::mutate(|count| {
+		let query_id = TRANSFER_QUERY_IDS.saturating_add(*count);
+		count.saturating_inc();
+		query_id
+	});
+	<Outgoing<T>>::put(OutgoingTransfer {
+		destination,
+		query_id,
+		max_weight: response_weight::<T>(),
+		message_id: None,
+	});
+	let responder = destination;
+
+	let destination = Box::new(destination.into());
+	let beneficiary = Box::new(beneficiary.into());
+	let assets = Box::new(assets.into());
+	let result = match mode {
+		TransferMode::Teleport => <PalletXcm<T>>::limited_teleport_assets(
+			origin,
+			destination,
+			beneficiary,
+			assets,
+			fee_asset_item,
+			weight_limit,
+		),
+		// The asset is kept on the sovereign account of the destination
+		TransferMode::Reserve => <PalletXcm<T>>::limited_reserve_transfer_assets(
+			origin,
+			destination,
+			beneficiary,
+			assets,
+			fee_asset_item,
+			weight_limit,
+		),
+	};
+	let outgoing = <Outgoing<T>>::take();
+	result.map_err(dispatch_to_evm::<T>)?;
+
+	let message_id = outgoing
+		.and_then(|outgoing| outgoing.message_id)
+		.ok_or("transfer message was not sent")?;
+	<Transfers<T>>::insert(message_id, TransferStatus::Pending);
+	<TransferQueries<T>>::insert(query_id, (responder, message_id));
+	<TransferExpiries<T>>::insert(timeout, query_id, message_id);
+	Ok(message_id)
+}
+
+/// Returns the status of a cross-chain transfer for the precompiles:
+/// 0 - unknown, 1 - pending, 2 - delivered, 3 - failed, 4 - trapped.
+///
+/// Transfers are unknown once their responses time out, whether reported or not.
+pub fn status_code<T: Config>(message_id: H256) -> u8 {
+	match <Pallet<T>>::transfer_status(&message_id.0) {
+		None => 0,
+		Some(TransferStatus::Pending) => 1,
+		Some(TransferStatus::Delivered) => 2,
+		Some(TransferStatus::Failed) => 3,
+		Some(TransferStatus::Trapped) => 4,
+	}
+}
+
+/// Router, which records the id of the message sent by [`transfer`] and asks the destination
+/// to report the outcome of its execution. Other messages are passed to `Inner` as is.
+///
+/// Must wrap the router assigning the ids to the messages, e.g. `WithUniqueTopic`.
+pub struct TrackTransfers<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T: Config, Inner: SendXcm> SendXcm for TrackTransfers<T, Inner> {
+	type Ticket = (Inner::Ticket, bool);
+
+	fn validate(
+		destination: &mut Option<MultiLocation>,
+		message: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		let tracked = match (<Outgoing<T>>::get(), destination.as_ref(), message.as_mut()) {
+			(Some(outgoing), Some(destination), Some(message))
+				if outgoing.message_id.is_none() && outgoing.destination == *destination =>
+			{
+				if !report_error::<T>(&outgoing, message) {
+					log::warn!(
+						target: LOG_TARGET,
+						"Unable to request the outcome of the transfer to {:?}",
+						destination
+					);
+				}
+				true
+			}
+			_ => false,
+		};
+
+		let (ticket, price) = Inner::validate(destination, message)?;
+		Ok(((ticket, tracked), price))
+	}
+
+	fn deliver((ticket, tracked): Self::Ticket) -> core::result::Result<XcmHash, SendError> {
+		let message_id = Inner::deliver(ticket)?;
+		if tracked {
+			<Outgoing<T>>::mutate(|outgoing| {
+				if let Some(outgoing) = outgoing {
+					outgoing.message_id = Some(message_id);
+				}
+			});
+		}
+		Ok(message_id)
+	}
+}
+
+/// Appends the error report to the message, right after the execution is bought,
+/// so the message still passes the barriers of the destination.
+///
+/// The appendix is executed after the `ClearOrigin` put by the teleport and the reserve transfer,
+/// so the response is sent without the querier, which is accepted by [`TransferResponses`].
+fn report_error<T: Config>(outgoing: &OutgoingTransfer, message: &mut Xcm<()>) -> bool {
+	let Ok(destination) = T::UniversalLocation::get().invert_target(&outgoing.destination) else {
+		return false;
+	};
+	let Some(index) = message
+		.0
+		.iter()
+		.position(|instruction| matches!(instruction, Instruction::BuyExecution { .. }))
+	else {
+		return false;
+	};
+
+	let response_info = QueryResponseInfo {
+		destination,
+		query_id: outgoing.query_id,
+		max_weight: outgoing.max_weight,
+	};
+	message.0.insert(
+		index + 1,
+		Instruction::SetAppendix(Xcm(sp_std::vec![Instruction::ReportError(response_info)])),
+	);
+	true
+}
+
+/// Weight of recording the outcome of a transfer by [`TransferResponses`].
+fn response_weight<T: Config>() -> Weight {
+	T::DbWeight::get().reads_writes(1, 2)
+}
+
+/// Response handler, which records the outcome of the transfers sent by [`transfer`],
+/// and passes other responses to `Inner`, e.g. `pallet_xcm`.
+///
+/// Responses to the transfer queries come without the querier, since the origin is cleared
+/// on the destination before the outcome is reported, so they are only checked to come
+/// from the destination of the transfer.
+pub struct TransferResponses<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T: Config, Inner: OnResponse> OnResponse for TransferResponses<T, Inner> {
+	fn expecting_response(
+		origin: &MultiLocation,
+		query_id: QueryId,
+		querier: Option<&MultiLocation>,
+	) -> bool {
+		match <TransferQueries<T>>::get(query_id) {
+			Some((responder, _)) => responder == *origin,
+			None => Inner::expecting_response(origin, query_id, querier),
+		}
+	}
+
+	fn on_response(
+		origin: &MultiLocation,
+		query_id: QueryId,
+		querier: Option<&MultiLocation>,
+		response: Response,
+		max_weight: Weight,
+		context: &XcmContext,
+	) -> Weight {
+		let Some((responder, message_id)) = <TransferQueries<T>>::get(query_id) else {
+			return Inner::on_response(origin, query_id, querier, response, max_weight, context);
+		};
+		let status = TransferStatus::from_response(&response);
+		let Some(status) = status.filter(|_| responder == *origin) else {
+			log::warn!(
+				target: LOG_TARGET,
+				"Unexpected response to the transfer query {} from {:?}: {:?}",
+				query_id,
+				origin,
+				response
+			);
+			return T::DbWeight::get().reads(1);
+		};
+
+		<TransferQueries<T>>::remove(query_id);
+		<Transfers<T>>::insert(message_id, status);
+		<Pallet<T>>::deposit_event(Event::TransferExecuted { message_id, status });
+		response_weight::<T>()
+	}
+}
@@ -0,0 +1,234 @@
+//! Batch precompile, modelled on Moonbeam's `Batch`: performs several subcalls
+//! in a single transaction on behalf of its caller.
+//!
+//! All of the functions take the same arguments, `(address[] to, uint256[] value,
+//! bytes[] callData, uint64[] gasLimit)`. Only `to` is required to be complete, missing
+//! values and call data are zero and empty, a missing or zero gas limit forwards all of the
+//! remaining gas. Every subcall emits either `SubcallSucceeded(uint256 index)`
+//! or `SubcallFailed(uint256 index)`.
+
+use core::marker::PhantomData;
+
+use evm_coder::{
+	types::{Address, Bytes},
+	AbiDecode, AbiEncode, ToLog,
+};
+use fp_evm::{
+	Context, ExitError, ExitReason, ExitRevert, ExitSucceed, Precompile, PrecompileFailure,
+	PrecompileHandle, PrecompileOutput, PrecompileResult, Transfer,
+};
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use sp_core::U256;
+use sp_runtime::DispatchError;
+use sp_std::{iter, vec::Vec};
+
+// Selector for batchSome(address[] to, uint256[] value, bytes[] callData, uint64[] gasLimit)
+pub const BATCH_SOME: [u8; 4] = hex_literal::hex!("79DF4B9C");
+// Selector for batchSomeUntilFailure(address[] to, uint256[] value, bytes[] callData, uint64[] gasLimit)
+pub const BATCH_SOME_UNTIL_FAILURE: [u8; 4] = hex_literal::hex!("CF0491C7");
+// Selector for batchAll(address[] to, uint256[] value, bytes[] callData, uint64[] gasLimit)
+pub const BATCH_ALL: [u8; 4] = hex_literal::hex!("96E292B8");
+
+// Gas of `LOG*` opcodes
+const G_LOG: u64 = 375;
+const G_LOGTOPIC: u64 = 375;
+const G_LOGDATA: u64 = 8;
+// Gas of a `CALL` transferring value to a new account
+const G_CALLVALUE: u64 = 9000;
+const G_NEWACCOUNT: u64 = 25000;
+
+#[derive(ToLog)]
+pub enum BatchEvents {
+	SubcallSucceeded { index: U256 },
+	SubcallFailed { index: U256 },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+	/// Failed subcalls are skipped.
+	BatchSome,
+	/// The first failed subcall stops the batch, preceding subcalls are kept.
+	BatchSomeUntilFailure,
+	/// The first failed subcall reverts the whole batch.
+	BatchAll,
+}
+
+/// Subcalls are made with the caller of the batch as `msg.sender`, so the contracts,
+/// including the ones handled by `OnMethodCall`, see the same caller as for direct calls.
+pub struct Batch<R>(PhantomData<R>);
+
+impl<R: pallet_evm::Config> Precompile for Batch<R> {
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		// Subcalls on behalf of the caller of a contract must not be made by that contract
+		if handle.code_address() != handle.context().address {
+			return Err(revert("cannot be called with DELEGATECALL or CALLCODE"));
+		}
+		if handle.is_static() {
+			return Err(revert("cannot be called with STATICCALL"));
+		}
+		if !handle.context().apparent_value.is_zero() {
+			return Err(revert("function is not payable"));
+		}
+
+		let (selector, (to, value, call_data, gas_limit)) =
+			<(Vec<Address>, Vec<U256>, Vec<Bytes>, Vec<u64>)>::abi_decode_call(handle.input())
+				.map_err(|_| revert("invalid input"))?;
+		let mode = match selector {
+			BATCH_SOME => Mode::BatchSome,
+			BATCH_SOME_UNTIL_FAILURE => Mode::BatchSomeUntilFailure,
+			BATCH_ALL => Mode::BatchAll,
+			_ => return Err(revert("unknown selector")),
+		};
+
+		with_transaction(|| {
+			let result = batch::<R>(handle, mode, to, value, call_data, gas_limit);
+			if result.is_ok() {
+				TransactionOutcome::Commit(Ok::<_, DispatchError>(result))
+			} else {
+				TransactionOutcome::Rollback(Ok(result))
+			}
+		})
+		.unwrap_or_else(|_| Err(revert("transactional layer limit reached")))?;
+
+		Ok(PrecompileOutput {
+			exit_status: ExitSucceed::Returned,
+			output: Vec::new(),
+		})
+	}
+}
+
+fn batch<R: pallet_evm::Config>(
+	handle: &mut impl PrecompileHandle,
+	mode: Mode,
+	to: Vec<Address>,
+	value: Vec<U256>,
+	call_data: Vec<Bytes>,
+	gas_limit: Vec<u64>,
+) -> Result<(), PrecompileFailure> {
+	let caller = handle.context().caller;
+	let values = value.into_iter().chain(iter::repeat(U256::zero()));
+	let calls_data = call_data
+		.into_iter()
+		.map(|data| data.0)
+		.chain(iter::repeat_with(Vec::new));
+	let gas_limits = gas_limit.into_iter().chain(iter::repeat(0));
+
+	// Enough gas is kept to report the outcome of the subcall
+	let log = BatchEvents::SubcallFailed {
+		index: U256::zero(),
+	}
+	.to_log(handle.code_address());
+	let log_cost = log_cost(log.topics.len(), log.data.len());
+
+	for (index, (((address, value), data), gas_limit)) in to
+		.into_iter()
+		.zip(values)
+		.zip(calls_data)
+		.zip(gas_limits)
+		.enumerate()
+	{
+		let index = U256::from(index);
+		let Some(remaining_gas) = handle.remaining_gas().checked_sub(log_cost) else {
+			return match mode {
+				Mode::BatchAll => Err(PrecompileFailure::Error {
+					exit_status: ExitError::OutOfGas,
+				}),
+				Mode::BatchSome | Mode::BatchSomeUntilFailure => Ok(()),
+			};
+		};
+
+		let call_cost = call_cost(value, R::config());
+		let gas = match remaining_gas.checked_sub(call_cost) {
+			Some(remaining_gas) if gas_limit == 0 => Some(remaining_gas),
+			Some(remaining_gas) if gas_limit <= remaining_gas => Some(gas_limit),
+			_ => None,
+		};
+		let Some(gas) = gas else {
+			emit(handle, BatchEvents::SubcallFailed { index })?;
+			match mode {
+				Mode::BatchAll => {
+					return Err(PrecompileFailure::Error {
+						exit_status: ExitError::OutOfGas,
+					})
+				}
+				Mode::BatchSomeUntilFailure => return Ok(()),
+				Mode::BatchSome => continue,
+			}
+		};
+		handle.record_cost(call_cost)?;
+
+		let transfer = (!value.is_zero()).then_some(Transfer {
+			source: caller,
+			target: address,
+			value,
+		});
+		let context = Context {
+			address,
+			caller,
+			apparent_value: value,
+		};
+		let (reason, output) = handle.call(address, transfer, data, Some(gas), false, &context);
+
+		match reason {
+			ExitReason::Succeed(_) => emit(handle, BatchEvents::SubcallSucceeded { index })?,
+			ExitReason::Revert(_) | ExitReason::Error(_) => {
+				emit(handle, BatchEvents::SubcallFailed { index })?
+			}
+			_ => {}
+		}
+
+		match (mode, reason) {
+			(_, ExitReason::Fatal(exit_status)) => {
+				return Err(PrecompileFailure::Fatal { exit_status })
+			}
+			(Mode::BatchAll, ExitReason::Revert(exit_status)) => {
+				return Err(PrecompileFailure::Revert {
+					exit_status,
+					output,
+				})
+			}
+			(Mode::BatchAll, ExitReason::Error(exit_status)) => {
+				return Err(PrecompileFailure::Error { exit_status })
+			}
+			(Mode::BatchSomeUntilFailure, ExitReason::Revert(_) | ExitReason::Error(_)) => {
+				return Ok(())
+			}
+			_ => {}
+		}
+	}
+
+	Ok(())
+}
+
+fn emit(handle: &mut impl PrecompileHandle, event: BatchEvents) -> Result<(), ExitError> {
+	let log = event.to_log(handle.code_address());
+	handle.record_cost(log_cost(log.topics.len(), log.data.len()))?;
+	handle.log(log.address, log.topics, log.data)
+}
+
+fn log_cost(topics: usize, data_len: usize) -> u64 {
+	G_LOG
+		.saturating_add(G_LOGTOPIC.saturating_mul(topics as u64))
+		.saturating_add(G_LOGDATA.saturating_mul(data_len as u64))
+}
+
+/// Cost of a subcall paid by the batch itself, as for a `CALL` to a cold account.
+fn call_cost(value: U256, config: &fp_evm::Config) -> u64 {
+	let access = if config.increase_state_access_gas {
+		config.gas_account_access_cold
+	} else {
+		config.gas_call
+	};
+	if value.is_zero() {
+		access
+	} else {
+		access + G_CALLVALUE + G_NEWACCOUNT
+	}
+}
+
+fn revert(message: &str) -> PrecompileFailure {
+	PrecompileFailure::Revert {
+		exit_status: ExitRevert::Reverted,
+		output: (message,).abi_encode_call(evm_coder::fn_selector!(Error(string))),
+	}
+}
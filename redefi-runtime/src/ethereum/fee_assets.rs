@@ -0,0 +1,226 @@
+use frame_support::traits::tokens::ConversionToAssetBalance;
+use pallet_evm::account::CrossAccountId as _;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding, FixedPointNumber, FixedU128, Rounding,
+};
+use xcm::latest::AssetId as XcmAssetId;
+
+use super::CrossAccountId;
+use crate::{xcm_config::ThisNetwork, *};
+
+parameter_types! {
+	/// H160 mirror of the Treasury account, EVM fees paid in assets are collected there.
+	pub TreasuryEthAddress: H160 = *CrossAccountId::from_sub(Treasury::account_id()).as_eth();
+}
+
+/// Converts native fees to `EvmAssets` at the rates of `AssetRate`.
+///
+/// The rate of an asset is looked up by its location on this chain,
+/// which is the `AccountKey20` of the asset contract, same as in XCM.
+pub struct EvmAssetRate;
+
+impl EvmAssetRate {
+	pub fn asset_kind(asset_id: u128) -> VersionedLocatableAsset {
+		VersionedLocatableAsset::V3 {
+			location: Location::here(),
+			asset_id: XcmAssetId::Concrete(
+				Junction::AccountKey20 {
+					network: Some(ThisNetwork::get()),
+					key: EvmAssets::asset_id_to_address(&asset_id).into(),
+				}
+				.into(),
+			),
+		}
+	}
+}
+
+impl ConversionToAssetBalance<Balance, u128, Balance> for EvmAssetRate {
+	type Error = ();
+
+	fn to_asset_balance(balance: Balance, asset_id: u128) -> Result<Balance, Self::Error> {
+		let rate =
+			pallet_asset_rate::ConversionRateToNative::<Runtime>::get(Self::asset_kind(asset_id))
+				.ok_or(())?;
+
+		// The rate is the price of the asset in the native currency
+		multiply_by_rational_with_rounding(balance, FixedU128::DIV, rate.into_inner(), Rounding::Up)
+			.ok_or(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use fp_evm::WithdrawReason;
+	use frame_support::assert_ok;
+	use pallet_evm::OnChargeEVMTransaction;
+	use pallet_evm_sponsorship::types::{SponsoredTarget, SponsorshipRule};
+
+	use super::*;
+
+	type FeeAdapter = <Runtime as pallet_evm::Config>::OnChargeTransaction;
+
+	const FEE_ASSET: u128 = 1;
+	const OWNER: H160 = H160([0x11; 20]);
+	const CALLER: H160 = H160([0x22; 20]);
+	const TARGET: H160 = H160([0x33; 20]);
+	const INITIAL: Balance = 1_000;
+
+	fn with_rate(rate: Option<FixedU128>, f: impl FnOnce()) {
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into();
+		ext.execute_with(|| {
+			if let Some(rate) = rate {
+				pallet_asset_rate::ConversionRateToNative::<Runtime>::insert(
+					EvmAssetRate::asset_kind(1),
+					rate,
+				);
+			}
+			f()
+		})
+	}
+
+	#[test]
+	fn converts_at_asset_price() {
+		// 1 asset costs 2 native
+		with_rate(Some(FixedU128::from_u32(2)), || {
+			assert_eq!(EvmAssetRate::to_asset_balance(10, 1), Ok(5));
+			assert_eq!(EvmAssetRate::to_asset_balance(11, 1), Ok(6));
+		});
+	}
+
+	#[test]
+	fn requires_rate() {
+		with_rate(None, || {
+			assert_eq!(EvmAssetRate::to_asset_balance(10, 1), Err(()));
+		});
+		with_rate(Some(FixedU128::from_u32(0)), || {
+			assert_eq!(EvmAssetRate::to_asset_balance(10, 1), Err(()));
+		});
+	}
+
+	fn sub(address: H160) -> AccountId {
+		CrossAccountId::from_eth(address).as_sub().clone()
+	}
+
+	fn call() -> WithdrawReason {
+		WithdrawReason::Call {
+			target: TARGET,
+			input: Vec::new(),
+			max_fee_per_gas: Some(U256::from(1)),
+			gas_limit: 100,
+			is_transactional: true,
+			is_check: false,
+		}
+	}
+
+	/// The caller holds both the native currency and the asset, which costs 2 native.
+	fn with_fee_asset(f: impl FnOnce()) {
+		with_rate(Some(FixedU128::from_u32(2)), || {
+			assert_ok!(EvmAssets::force_create(
+				RuntimeOrigin::root(),
+				FEE_ASSET,
+				OWNER,
+				b"Fee".to_vec(),
+				b"FEE".to_vec(),
+				18,
+			));
+			assert_ok!(EvmAssets::mint(&FEE_ASSET, &CALLER, INITIAL));
+			Balances::make_free_balance_be(&sub(CALLER), INITIAL);
+			f()
+		})
+	}
+
+	#[test]
+	fn fee_is_paid_in_asset_and_refunded() {
+		with_fee_asset(|| {
+			assert_ok!(EvmAssets::set_fee_asset(&CALLER, Some(FEE_ASSET)));
+			let who = CrossAccountId::from_eth(CALLER);
+
+			let paid = FeeAdapter::withdraw_fee(&who, call(), U256::from(100)).unwrap();
+			assert_eq!(EvmAssets::balance(&FEE_ASSET, &CALLER), INITIAL - 50);
+			assert_eq!(
+				EvmAssets::balance(&FEE_ASSET, &TreasuryEthAddress::get()),
+				50
+			);
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL);
+
+			// The used fee is rounded up in favour of the collector
+			FeeAdapter::correct_and_deposit_fee(&who, U256::from(41), U256::zero(), paid);
+			assert_eq!(EvmAssets::balance(&FEE_ASSET, &CALLER), INITIAL - 21);
+			assert_eq!(
+				EvmAssets::balance(&FEE_ASSET, &TreasuryEthAddress::get()),
+				21
+			);
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL);
+		});
+	}
+
+	#[test]
+	fn asset_fee_requires_balance() {
+		with_fee_asset(|| {
+			assert_ok!(EvmAssets::set_fee_asset(&CALLER, Some(FEE_ASSET)));
+			let who = CrossAccountId::from_eth(CALLER);
+
+			assert!(FeeAdapter::withdraw_fee(&who, call(), U256::from(2 * INITIAL + 2)).is_err());
+			assert_eq!(EvmAssets::balance(&FEE_ASSET, &CALLER), INITIAL);
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL);
+		});
+	}
+
+	#[test]
+	fn fee_is_paid_natively_without_fee_asset_or_rate() {
+		with_fee_asset(|| {
+			let who = CrossAccountId::from_eth(CALLER);
+
+			let paid = FeeAdapter::withdraw_fee(&who, call(), U256::from(100)).unwrap();
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL - 100);
+			FeeAdapter::correct_and_deposit_fee(&who, U256::from(40), U256::zero(), paid);
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL - 40);
+			assert_eq!(EvmAssets::balance(&FEE_ASSET, &CALLER), INITIAL);
+
+			// The asset can't be priced
+			assert_ok!(EvmAssets::set_fee_asset(&CALLER, Some(FEE_ASSET)));
+			pallet_asset_rate::ConversionRateToNative::<Runtime>::remove(EvmAssetRate::asset_kind(
+				FEE_ASSET,
+			));
+			let paid = FeeAdapter::withdraw_fee(&who, call(), U256::from(100)).unwrap();
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL - 140);
+			FeeAdapter::correct_and_deposit_fee(&who, U256::from(100), U256::zero(), paid);
+			assert_eq!(EvmAssets::balance(&FEE_ASSET, &CALLER), INITIAL);
+		});
+	}
+
+	#[test]
+	fn sponsored_fee_is_paid_natively_by_sponsor() {
+		with_fee_asset(|| {
+			let sponsor = sub(H160([0x44; 20]));
+			Balances::make_free_balance_be(&sponsor, INITIAL);
+			assert_ok!(EvmSponsorship::create_rule(
+				RuntimeOrigin::root(),
+				SponsorshipRule {
+					target: SponsoredTarget::Contract {
+						address: TARGET,
+						selector: [0; 4],
+						selector_mask: [0; 4],
+					},
+					sponsor: sponsor.clone(),
+					rate_limit: 0,
+					budget: None,
+					period: 0,
+				}
+			));
+			assert_ok!(EvmAssets::set_fee_asset(&CALLER, Some(FEE_ASSET)));
+			let who = CrossAccountId::from_eth(CALLER);
+
+			let paid = FeeAdapter::withdraw_fee(&who, call(), U256::from(100)).unwrap();
+			assert_eq!(Balances::free_balance(&sponsor), INITIAL - 100);
+			FeeAdapter::correct_and_deposit_fee(&who, U256::from(40), U256::zero(), paid);
+			assert_eq!(Balances::free_balance(&sponsor), INITIAL - 40);
+
+			assert_eq!(EvmAssets::balance(&FEE_ASSET, &CALLER), INITIAL);
+			assert_eq!(Balances::free_balance(sub(CALLER)), INITIAL);
+		});
+	}
+}
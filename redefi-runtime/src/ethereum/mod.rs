@@ -12,10 +12,17 @@ use polkadot_runtime_constants::{system_parachain::RED_ID, TOKEN_SYMBOL};
 use sp_runtime::{traits::ConstU32, Perbill, RuntimeAppPublic};
 
 use crate::*;
+pub mod batch;
+pub mod precompiles;
 pub mod self_contained_call;
+use precompiles::RedefiPrecompiles;
 
+mod fee_assets;
+pub use fee_assets::{EvmAssetRate, TreasuryEthAddress};
 mod sponsoring;
-use sponsoring::EthCrossChainTransferSponsorshipHandler;
+pub use sponsoring::{
+	ContractOwners, CrossChainTransferSponsorshipRules, FactoryApprovedDeployers, OwnAssetTransfers,
+};
 
 pub type CrossAccountId = pallet_evm::account::BasicCrossAccountId<Runtime>;
 
@@ -70,6 +77,10 @@ impl<T: pallet_evm::Config> fp_evm::FeeCalculator for FeeCalculator<T> {
 
 pub type DealWithFees = Treasury;
 
+parameter_types! {
+	pub PrecompilesValue: RedefiPrecompiles<Runtime> = RedefiPrecompiles::<_>::new();
+}
+
 use fp_evm::WithdrawReason;
 use frame_support::traits::{Currency, Imbalance, OnUnbalanced};
 use pallet_evm::OnChargeEVMTransaction;
@@ -86,24 +97,32 @@ impl pallet_evm::Config for Runtime {
 	type BlockHashMapping = pallet_ethereum::EthereumBlockHashMapping<Self>;
 	type CallOrigin = EnsureAddressTruncated<Self>;
 	type WithdrawOrigin = EnsureAddressTruncated<Self>;
-	type PrecompilesType = ();
-	type PrecompilesValue = ();
+	type PrecompilesType = RedefiPrecompiles<Self>;
+	type PrecompilesValue = PrecompilesValue;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type OnMethodCall = (
 		pallet_balances_adapter::eth::AdapterOnMethodCall<Self>,
+		pallet_evm_assets::factory::FactoryOnMethodCall<Self>,
 		pallet_evm_assets::eth::AdapterOnMethodCall<Self>,
+		pallet_evm_contract_helpers::eth::HelpersOnMethodCall<Self>,
 	);
-	type OnCreate = ();
+	type OnCreate = EvmContractHelpers;
 	type ChainId = ChainId;
 	type Runner = pallet_evm::runner::stack::Runner<Self>;
-	type OnChargeTransaction =
-		pallet_evm_transaction_payment::WrappedEVMCurrencyAdapter<Balances, DealWithFees>;
+	type OnChargeTransaction = pallet_evm_assets::fee::EvmAssetsFeeAdapter<
+		pallet_evm_transaction_payment::WrappedEVMCurrencyAdapter<Balances, DealWithFees>,
+		EvmAssetRate,
+		TreasuryEthAddress,
+	>;
 	type FindAuthor = EthereumFindAuthor<Babe>;
 	type Timestamp = crate::Timestamp;
 	type WeightInfo = pallet_evm::weights::SubstrateWeight<Self>;
 	type GasLimitPovSizeRatio = ProofSizePerGas;
-	type OnCheckEvmTransaction = pallet_evm_transaction_payment::TransactionValidity<Self>;
+	type OnCheckEvmTransaction = pallet_evm_assets::fee::FeeAssetValidity<
+		pallet_evm_transaction_payment::TransactionValidity<Self>,
+		EvmAssetRate,
+	>;
 }
 
 parameter_types! {
@@ -122,7 +141,35 @@ impl pallet_ethereum::Config for Runtime {
 impl pallet_evm_coder_substrate::Config for Runtime {}
 
 impl pallet_evm_transaction_payment::Config for Runtime {
-	type EvmSponsorshipHandler = EthCrossChainTransferSponsorshipHandler<Self>;
+	type EvmSponsorshipHandler = (OwnAssetTransfers<EvmSponsorship>, EvmContractHelpers);
+	type EvmCreateSponsorshipHandler = EvmSponsorship;
+	type SubstrateSponsorshipHandler = EvmSponsorship;
+}
+
+parameter_types! {
+	pub const MaxSponsorshipRulesPerTarget: u32 = 16;
+	pub const SponsorshipRuleDeposit: Balance = 100 * DOLLARS;
+}
+
+impl pallet_evm_sponsorship::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	type MaxRulesPerTarget = MaxSponsorshipRulesPerTarget;
+	type Currency = Balances;
+	type RuleDeposit = SponsorshipRuleDeposit;
+	type ApprovedDeployers = FactoryApprovedDeployers;
+	type ContractOwners = ContractOwners;
+}
+
+parameter_types! {
+	pub const ContractHelpersAddress: H160 = H160(hex_literal::hex!("BABB000000000000000000000000000000000002"));
+	pub const DefaultSponsoringRateLimit: BlockNumber = DAYS;
+}
+
+impl pallet_evm_contract_helpers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ContractAddress = ContractHelpersAddress;
+	type DefaultSponsoringRateLimit = DefaultSponsoringRateLimit;
 }
 
 parameter_types! {
@@ -140,10 +187,34 @@ pub const RED_CHAIN_ID: u64 = 1899;
 pub const RED_CHAIN_ID: u64 = 11899;
 
 parameter_types! {
-	pub ChainLocator: BTreeMap<u64, Location> = BTreeMap::from([(RED_CHAIN_ID, Junction::Parachain(RED_ID).into_location())]);
+	/// Chains registered when `ChainRegistry` is deployed.
+	pub KnownChains: Vec<(u64, Location)> = vec![(RED_CHAIN_ID, Junction::Parachain(RED_ID).into_location())];
+}
+
+parameter_types! {
+	pub const CrossChainTransferResponseTimeout: BlockNumber = HOURS;
+}
+
+impl pallet_chain_registry::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+}
+
+impl pallet_xcm_transfers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// BAX goes first, its address has the prefix of `EvmAssets`
+	type TransferableAssets = (BalancesAdapter, EvmAssets);
+	type TransferResponseTimeout = CrossChainTransferResponseTimeout;
+}
+
+impl pallet_bridge_flows::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ManagerOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	type ChainIds = ChainRegistry;
 }
 
 impl pallet_balances_adapter::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type Balances = Balances;
 	type NativeBalance = Balance;
 	type ContractAddress = AdapterContractAddress;
@@ -151,16 +222,25 @@ impl pallet_balances_adapter::Config for Runtime {
 	type Name = Name;
 	type Symbol = Symbol;
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Self>;
-	type ChainLocator = ChainLocator;
+	type ChainLocator = ChainRegistry;
 }
 
 parameter_types! {
 	pub Prefix: [u8; 4] = [0xFF, 0xFF, 0xFF, 0xFF];
 	pub StringLimit: u32 = 32;
+	pub const EvmAssetsFactoryAddress: H160 = H160(hex_literal::hex!("BABB000000000000000000000000000000000001"));
+	pub const EvmAssetDeposit: Balance = 100 * DOLLARS;
 }
 
 impl pallet_evm_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ForceOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
 	type AddressPrefix = Prefix;
 	type StringLimit = StringLimit;
-	type ChainLocator = ChainLocator;
+	type ChainLocator = ChainRegistry;
+	type FactoryAddress = EvmAssetsFactoryAddress;
+	type DepositCurrency = Balances;
+	type AssetDeposit = EvmAssetDeposit;
+	type CheckAccount = xcm_config::EvmCheckAccount;
+	type WeightInfo = pallet_evm_assets::weights::SubstrateWeight<Runtime>;
 }
@@ -0,0 +1,333 @@
+use core::marker::PhantomData;
+
+use pallet_evm::{
+	IsPrecompileResult, Precompile, PrecompileHandle, PrecompileResult, PrecompileSet,
+};
+use pallet_evm_precompile_blake2::Blake2F;
+use pallet_evm_precompile_bn128::{Bn128Add, Bn128Mul, Bn128Pairing};
+use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_sha3fips::Sha3FIPS256;
+use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use sp_core::H160;
+
+use super::batch::Batch;
+
+/// Standard Ethereum precompiles (Istanbul/Berlin, 0x01 - 0x09),
+/// plus non-standard `Sha3FIPS256` (0x0400), `ECRecoverPublicKey` (0x0401) and `Batch` (0x0808).
+pub struct RedefiPrecompiles<R>(PhantomData<R>);
+
+impl<R> Default for RedefiPrecompiles<R> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<R> RedefiPrecompiles<R>
+where
+	R: pallet_evm::Config,
+{
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn used_addresses() -> [H160; 12] {
+		[
+			hash(1),
+			hash(2),
+			hash(3),
+			hash(4),
+			hash(5),
+			hash(6),
+			hash(7),
+			hash(8),
+			hash(9),
+			hash(1024),
+			hash(1025),
+			hash(2056),
+		]
+	}
+}
+
+impl<R> PrecompileSet for RedefiPrecompiles<R>
+where
+	R: pallet_evm::Config,
+{
+	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+		match handle.code_address() {
+			// Ethereum precompiles:
+			a if a == hash(1) => Some(ECRecover::execute(handle)),
+			a if a == hash(2) => Some(Sha256::execute(handle)),
+			a if a == hash(3) => Some(Ripemd160::execute(handle)),
+			a if a == hash(4) => Some(Identity::execute(handle)),
+			a if a == hash(5) => Some(Modexp::execute(handle)),
+			a if a == hash(6) => Some(Bn128Add::execute(handle)),
+			a if a == hash(7) => Some(Bn128Mul::execute(handle)),
+			a if a == hash(8) => Some(Bn128Pairing::execute(handle)),
+			a if a == hash(9) => Some(Blake2F::execute(handle)),
+			// Non-standard precompiles:
+			a if a == hash(1024) => Some(Sha3FIPS256::execute(handle)),
+			a if a == hash(1025) => Some(ECRecoverPublicKey::execute(handle)),
+			a if a == hash(2056) => Some(Batch::<R>::execute(handle)),
+			_ => None,
+		}
+	}
+
+	fn is_precompile(&self, address: H160, _gas: u64) -> IsPrecompileResult {
+		IsPrecompileResult::Answer {
+			is_precompile: Self::used_addresses().contains(&address),
+			extra_cost: 0,
+		}
+	}
+}
+
+fn hash(a: u64) -> H160 {
+	H160::from_low_u64_be(a)
+}
+
+#[cfg(test)]
+mod tests {
+	use evm_coder::{types::Bytes, AbiEncode};
+	use fp_evm::{CallInfo, ExitReason, ExitSucceed};
+	use hex_literal::hex;
+	use pallet_evm::{account::CrossAccountId as _, Runner as _};
+	use sp_core::{ecdsa, Pair, H256, U256};
+	use sp_io::hashing::keccak_256;
+
+	use super::*;
+	use crate::{
+		ethereum::{
+			batch::{BATCH_ALL, BATCH_SOME, BATCH_SOME_UNTIL_FAILURE},
+			AdapterContractAddress, CrossAccountId,
+		},
+		Runtime,
+	};
+
+	const CALLER: H160 = H160([0x11; 20]);
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap()
+			.into()
+	}
+
+	fn execute(address: H160, input: Vec<u8>) -> CallInfo {
+		<Runtime as pallet_evm::Config>::Runner::call(
+			CrossAccountId::from_eth(CALLER),
+			address,
+			input,
+			U256::zero(),
+			1_000_000,
+			None,
+			None,
+			None,
+			Vec::new(),
+			false,
+			false,
+			None,
+			None,
+			<Runtime as pallet_evm::Config>::config(),
+		)
+		.expect("call should be executed")
+	}
+
+	fn call_precompile(address: u64, input: Vec<u8>) -> Vec<u8> {
+		new_test_ext().execute_with(|| {
+			let info = execute(hash(address), input);
+
+			assert_eq!(
+				info.exit_reason,
+				ExitReason::Succeed(ExitSucceed::Returned),
+				"precompile {address} failed",
+			);
+			info.value
+		})
+	}
+
+	fn word(value: u64) -> [u8; 32] {
+		let mut word = [0; 32];
+		U256::from(value).to_big_endian(&mut word);
+		word
+	}
+
+	/// Returns `ecrecover` input along with the signer public key.
+	fn signed_message() -> (Vec<u8>, [u8; 64]) {
+		let pair = ecdsa::Pair::from_seed(&[0x42; 32]);
+		let message = keccak_256(b"redefi");
+		let signature = pair.sign_prehashed(&message);
+		let public = sp_io::crypto::secp256k1_ecdsa_recover(&signature.0, &message).unwrap();
+
+		let mut input = message.to_vec();
+		input.extend_from_slice(&word(signature.0[64] as u64 + 27));
+		input.extend_from_slice(&signature.0[..64]);
+		(input, public)
+	}
+
+	#[test]
+	fn ec_recover() {
+		let (input, public) = signed_message();
+		let mut expected = [0; 32];
+		expected[12..].copy_from_slice(&keccak_256(&public)[12..]);
+
+		assert_eq!(call_precompile(1, input), expected);
+	}
+
+	#[test]
+	fn sha256() {
+		assert_eq!(
+			call_precompile(2, Vec::new()),
+			hex!("e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+		);
+	}
+
+	#[test]
+	fn ripemd160() {
+		assert_eq!(
+			call_precompile(3, Vec::new()),
+			hex!("0000000000000000000000009c1185a5c5e9fc54612808977ee8f548b2258d31"),
+		);
+	}
+
+	#[test]
+	fn identity() {
+		assert_eq!(call_precompile(4, b"redefi".to_vec()), b"redefi");
+	}
+
+	#[test]
+	fn modexp() {
+		// 3 ** 2 % 5
+		let mut input = Vec::new();
+		input.extend_from_slice(&word(1));
+		input.extend_from_slice(&word(1));
+		input.extend_from_slice(&word(1));
+		input.extend_from_slice(&[3, 2, 5]);
+
+		assert_eq!(call_precompile(5, input), [4]);
+	}
+
+	#[test]
+	fn bn128_add() {
+		// Point at infinity plus itself
+		assert_eq!(call_precompile(6, vec![0; 128]), [0; 64]);
+	}
+
+	#[test]
+	fn bn128_mul() {
+		// Point at infinity multiplied by zero
+		assert_eq!(call_precompile(7, vec![0; 96]), [0; 64]);
+	}
+
+	#[test]
+	fn bn128_pairing() {
+		// Empty input is a valid pairing check
+		assert_eq!(call_precompile(8, Vec::new()), word(1));
+	}
+
+	#[test]
+	fn blake2f() {
+		// rounds (4 bytes), h (64 bytes), m (128 bytes), t (16 bytes), f (1 byte)
+		let mut input = vec![0; 213];
+		input[212] = 1;
+
+		assert_eq!(call_precompile(9, input).len(), 64);
+	}
+
+	#[test]
+	fn sha3_fips256() {
+		assert_eq!(
+			call_precompile(1024, Vec::new()),
+			hex!("a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"),
+		);
+	}
+
+	#[test]
+	fn ec_recover_public_key() {
+		let (input, public) = signed_message();
+
+		assert_eq!(call_precompile(1025, input), public);
+	}
+
+	/// Identity call, which succeeds, and `bn128Add` of an invalid point, which fails.
+	fn subcall(succeeds: bool) -> (H160, Vec<u8>) {
+		if succeeds {
+			(hash(4), b"redefi".to_vec())
+		} else {
+			(hash(6), vec![1; 128])
+		}
+	}
+
+	fn batch(selector: [u8; 4], subcalls: &[(H160, Vec<u8>)]) -> CallInfo {
+		let to = subcalls.iter().map(|(to, _)| *to).collect::<Vec<_>>();
+		let call_data = subcalls
+			.iter()
+			.map(|(_, data)| Bytes(data.clone()))
+			.collect::<Vec<_>>();
+		let input =
+			(to, Vec::<U256>::new(), call_data, Vec::<u64>::new()).abi_encode_call(selector);
+		execute(hash(2056), input)
+	}
+
+	/// Returns the outcomes of the subcalls reported by the batch.
+	fn outcomes(info: &CallInfo) -> Vec<(bool, u64)> {
+		info.logs
+			.iter()
+			.map(|log| {
+				let succeeded = log.topics[0] == H256(keccak_256(b"SubcallSucceeded(uint256)"));
+				(succeeded, U256::from_big_endian(&log.data).as_u64())
+			})
+			.collect()
+	}
+
+	#[test]
+	fn batch_all() {
+		new_test_ext().execute_with(|| {
+			let info = batch(BATCH_ALL, &[subcall(true), subcall(true)]);
+			assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(outcomes(&info), [(true, 0), (true, 1)]);
+
+			let info = batch(BATCH_ALL, &[subcall(true), subcall(false)]);
+			assert!(matches!(info.exit_reason, ExitReason::Error(_)));
+			assert!(info.logs.is_empty());
+		});
+	}
+
+	#[test]
+	fn batch_some() {
+		new_test_ext().execute_with(|| {
+			let info = batch(BATCH_SOME, &[subcall(false), subcall(true)]);
+			assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(outcomes(&info), [(false, 0), (true, 1)]);
+		});
+	}
+
+	#[test]
+	fn batch_some_until_failure() {
+		new_test_ext().execute_with(|| {
+			let info = batch(
+				BATCH_SOME_UNTIL_FAILURE,
+				&[subcall(true), subcall(false), subcall(true)],
+			);
+			assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(outcomes(&info), [(true, 0), (false, 1)]);
+		});
+	}
+
+	#[test]
+	fn batch_keeps_caller() {
+		new_test_ext().execute_with(|| {
+			let spender = H160::repeat_byte(0x22);
+			// approve(address spender, uint256 amount)
+			let approve = (spender, U256::from(100)).abi_encode_call(hex!("095EA7B3"));
+
+			let info = batch(BATCH_ALL, &[(AdapterContractAddress::get(), approve)]);
+			assert_eq!(info.exit_reason, ExitReason::Succeed(ExitSucceed::Returned));
+			assert_eq!(
+				pallet_balances_adapter::Pallet::<Runtime>::allowance(
+					&CrossAccountId::from_eth(CALLER),
+					&CrossAccountId::from_eth(spender),
+				),
+				U256::from(100),
+			);
+		});
+	}
+}
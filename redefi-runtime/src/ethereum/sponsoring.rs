@@ -1,29 +1,118 @@
-use core::marker::PhantomData;
-
-use pallet_evm::account::CrossAccountId;
+use evm_coder::{types::Address, AbiDecode};
+use frame_support::traits::ContainsPair;
+use pallet_evm_sponsorship::{
+	types::{SponsoredTarget, SponsorshipRule},
+	SponsorshipRuleOf,
+};
 use pallet_evm_transaction_payment::CallContext;
 use up_sponsorship::SponsorshipHandler;
 
-// TODO: Move addresses to config/ethereum.rs.
+use crate::*;
+
 const BAX: [u8; 20] = hex_literal::hex!("FFFFFFFF0000000000000000000000000000BABB");
 const RED: [u8; 20] = hex_literal::hex!("FFFFFFFFBABB0000000000000000000000000000");
 const GBP: [u8; 20] = hex_literal::hex!("FFFFFFFFBABB0000000000000000000000000010");
 
 // Selector for crossChainTransfer(uint64 chainId, address receiver, uint256 amount)
 const CROSS_CHAIN_TRANSFER: [u8; 4] = hex_literal::hex!("EE18D38E");
+// Selector for crossChainTransferToSubstrate(uint64 chainId, bytes32 receiver, uint256 amount)
+const CROSS_CHAIN_TRANSFER_TO_SUBSTRATE: [u8; 4] = hex_literal::hex!("6D3002D2");
+// Selector for crossChainTransfer(uint64 chainId, address receiver, (address,uint256)[] assets, address feeAsset, uint64 weightLimit)
+const CROSS_CHAIN_TRANSFER_ASSETS: [u8; 4] = hex_literal::hex!("F3F85C1C");
+// Selector for crossChainTransferToSubstrate(uint64 chainId, bytes32 receiver, (address,uint256)[] assets, address feeAsset, uint64 weightLimit)
+const CROSS_CHAIN_TRANSFER_ASSETS_TO_SUBSTRATE: [u8; 4] = hex_literal::hex!("9DE8BC75");
 
-pub struct EthCrossChainTransferSponsorshipHandler<T>(PhantomData<T>);
+parameter_types! {
+	/// A caller is sponsored once per hour for every selector of every contract.
+	pub const CrossChainTransferSponsoringRateLimit: BlockNumber = HOURS;
+	/// The Treasury pays at most this much for each rule per budget period.
+	pub const CrossChainTransferSponsoringBudget: Balance = 1_000 * DOLLARS;
+	pub const CrossChainTransferSponsoringPeriod: BlockNumber = DAYS;
+
+	/// Cross-chain transfers of BAX, RED and GBP are sponsored by the Treasury,
+	/// these rules replace the handler that used to hard-code it.
+	/// Multi-asset transfers are only sponsored for the own asset, see [`OwnAssetTransfers`].
+	pub CrossChainTransferSponsorshipRules: Vec<SponsorshipRuleOf<Runtime>> = [BAX, RED, GBP]
+		.into_iter()
+		.flat_map(|contract| {
+			[
+				CROSS_CHAIN_TRANSFER,
+				CROSS_CHAIN_TRANSFER_TO_SUBSTRATE,
+				CROSS_CHAIN_TRANSFER_ASSETS,
+				CROSS_CHAIN_TRANSFER_ASSETS_TO_SUBSTRATE,
+			]
+			.map(|selector| (contract, selector))
+		})
+		.map(|(contract, selector)| SponsorshipRule {
+			target: SponsoredTarget::Contract {
+				address: H160(contract),
+				selector,
+				selector_mask: [0xFF; 4],
+			},
+			sponsor: Treasury::account_id(),
+			rate_limit: CrossChainTransferSponsoringRateLimit::get(),
+			budget: Some(CrossChainTransferSponsoringBudget::get()),
+			period: CrossChainTransferSponsoringPeriod::get(),
+		})
+		.collect();
+}
 
-impl<T> SponsorshipHandler<T::CrossAccountId, CallContext>
-	for EthCrossChainTransferSponsorshipHandler<T>
+/// Multi-asset cross-chain transfers made through an asset contract are only passed
+/// to the `Inner` handler if the contract's own asset is the only one transferred,
+/// so the sponsor of the contract doesn't pay for transfers of other assets.
+pub struct OwnAssetTransfers<Inner>(PhantomData<Inner>);
+
+impl<Inner> SponsorshipHandler<CrossAccountId, CallContext> for OwnAssetTransfers<Inner>
 where
-	T: frame_system::Config + pallet_evm::Config,
-	T::AccountId: From<sp_runtime::AccountId32>,
+	Inner: SponsorshipHandler<CrossAccountId, CallContext>,
 {
-	fn get_sponsor(_who: &T::CrossAccountId, call: &CallContext) -> Option<T::CrossAccountId> {
-		let use_treasury = [&BAX, &RED, &GBP].contains(&call.contract_address.as_fixed_bytes())
-			&& call.input.starts_with(&CROSS_CHAIN_TRANSFER);
+	fn get_sponsor(who: &CrossAccountId, call: &CallContext) -> Option<CrossAccountId> {
+		let contract = call.contract_address;
+		if !contract.as_bytes().starts_with(&super::Prefix::get()) {
+			return Inner::get_sponsor(who, call);
+		}
+
+		let selector = call
+			.input
+			.get(..4)
+			.and_then(|selector| selector.try_into().ok());
+		let assets = match selector {
+			Some(CROSS_CHAIN_TRANSFER_ASSETS) => {
+				<(u64, Address, Vec<(Address, U256)>, Address, u64)>::abi_decode_call(&call.input)
+					.ok()
+					.map(|(_, (_, _, assets, _, _))| assets)
+			}
+			Some(CROSS_CHAIN_TRANSFER_ASSETS_TO_SUBSTRATE) => {
+				<(u64, H256, Vec<(Address, U256)>, Address, u64)>::abi_decode_call(&call.input)
+					.ok()
+					.map(|(_, (_, _, assets, _, _))| assets)
+			}
+			_ => return Inner::get_sponsor(who, call),
+		};
+		if !assets?.iter().all(|(asset, _)| *asset == contract) {
+			return None;
+		}
+
+		Inner::get_sponsor(who, call)
+	}
+}
+
+/// Deployers approved by the factory owner in the contract helpers,
+/// rules sponsoring contract creation only apply to them.
+pub struct FactoryApprovedDeployers;
+
+impl ContainsPair<H160, H160> for FactoryApprovedDeployers {
+	fn contains(factory: &H160, deployer: &H160) -> bool {
+		EvmContractHelpers::deployer_approved(factory, deployer)
+	}
+}
+
+/// Contract owners recorded by the contract helpers,
+/// signed origins can only create sponsorship rules for the contracts they own.
+pub struct ContractOwners;
 
-		use_treasury.then(|| T::CrossAccountId::from_sub(crate::Treasury::account_id().into()))
+impl ContainsPair<H160, H160> for ContractOwners {
+	fn contains(contract: &H160, owner: &H160) -> bool {
+		EvmContractHelpers::contract_owner(contract).as_ref() == Some(owner)
 	}
 }
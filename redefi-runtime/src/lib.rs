@@ -114,7 +114,7 @@ use xcm::{
 	},
 	VersionedMultiLocation,
 };
-use xcm_builder::{AccountKey20Aliases, FungiblesAdapter, NoChecking, PayOverXcm};
+use xcm_builder::{AccountKey20Aliases, FungiblesAdapter, PayOverXcm};
 use xcm_executor::{
 	traits::{ConvertLocation, WeightTrader},
 	Assets,
@@ -983,7 +983,9 @@ where
 			)),
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(tip),
+			pallet_evm_transaction_payment::ChargeTransactionPaymentWithSponsor::<Runtime>::from(
+				tip,
+			),
 			claims::PrevalidateAttests::<Runtime>::new(),
 			pallet_ethereum::FakeTransactionFinalizer::<Runtime>::decode(&mut [].as_slice())
 				.unwrap(),
@@ -1658,6 +1660,11 @@ construct_runtime! {
 		BalancesAdapter: pallet_balances_adapter = 106,
 		EvmAssets: pallet_evm_assets = 107,
 		EvmTransactionPayment: pallet_evm_transaction_payment = 110,
+		EvmSponsorship: pallet_evm_sponsorship = 111,
+		EvmContractHelpers: pallet_evm_contract_helpers = 112,
+		ChainRegistry: pallet_chain_registry = 113,
+		XcmTransfers: pallet_xcm_transfers = 114,
+		BridgeFlows: pallet_bridge_flows = 115,
 	}
 }
 
@@ -1680,7 +1687,7 @@ pub type SignedExtra = (
 	frame_system::CheckMortality<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_evm_transaction_payment::ChargeTransactionPaymentWithSponsor<Runtime>,
 	claims::PrevalidateAttests<Runtime>,
 	pallet_ethereum::FakeTransactionFinalizer<Runtime>,
 );
@@ -1705,7 +1712,17 @@ pub mod migrations {
 	use crate::*;
 
 	/// Unreleased migrations. Add new ones here:
-	pub type Unreleased = (pallet_evm_assets::migration::InitializationWithSudoAsHolder<Runtime>);
+	pub type Unreleased = (
+		pallet_evm_assets::migration::InitializationWithSudoAsHolder<Runtime>,
+		pallet_evm_assets::migration::UnlimitedExistingMinters<Runtime>,
+		pallet_evm_sponsorship::migration::SeedRules<
+			Runtime,
+			ethereum::CrossChainTransferSponsorshipRules,
+		>,
+		pallet_evm_sponsorship::migration::MigrateToTargets<Runtime>,
+		pallet_chain_registry::migration::SeedChains<Runtime, ethereum::KnownChains>,
+		xcm_config::ReconcileTeleportedSupply,
+	);
 }
 
 /// Unchecked extrinsic type as expected by this runtime (Frontier wrapped extr).
@@ -1773,6 +1790,8 @@ mod benches {
 		[pallet_referenda, Referenda]
 		[pallet_whitelist, Whitelist]
 		[pallet_asset_rate, AssetRate]
+		// ReDeFi
+		[pallet_evm_assets, EvmAssets]
 		// XCM
 		[pallet_xcm, XcmPallet]
 		[pallet_xcm_benchmarks::fungible, pallet_xcm_benchmarks::fungible::Pallet::<Runtime>]
@@ -2034,6 +2053,24 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_evm_assets::runtime_api::EvmAssetsApi<Block> for Runtime {
+		fn assets() -> Vec<pallet_evm_assets::runtime_api::AssetInfo> {
+			EvmAssets::api_assets()
+		}
+
+		fn balances_of(account: H160) -> Vec<(u128, u128)> {
+			EvmAssets::api_balances_of(&account)
+		}
+
+		fn allowances_of(owner: H160) -> Vec<(u128, H160, u128)> {
+			EvmAssets::api_allowances_of(&owner)
+		}
+
+		fn permissions_of(asset: u128, account: H160) -> u64 {
+			EvmAssets::api_permissions_of(&asset, &account)
+		}
+	}
+
 	impl tx_pool_api::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -2683,7 +2720,7 @@ mod test_fees {
 			frame_system::CheckMortality::<Runtime>::from(generic::Era::immortal()),
 			frame_system::CheckNonce::<Runtime>::from(1),
 			frame_system::CheckWeight::<Runtime>::new(),
-			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+			pallet_evm_transaction_payment::ChargeTransactionPaymentWithSponsor::<Runtime>::from(0),
 			claims::PrevalidateAttests::<Runtime>::new(),
 		);
 		let uxt = UncheckedExtrinsic(sp_runtime::generic::UncheckedExtrinsic {
@@ -18,10 +18,14 @@
 
 use frame_support::{
 	match_types, parameter_types,
-	traits::{ContainsPair, Equals, Everything, Nothing},
-	weights::Weight,
+	traits::{
+		fungibles, tokens::ConversionToAssetBalance, ContainsPair, Equals, Everything, Nothing,
+	},
+	weights::{Weight, WeightToFee as WeightToFeeT},
 };
 use frame_system::EnsureRoot;
+use pallet_bridge_flows::inflow::LimitInflow;
+use pallet_chain_registry::TransferMode;
 use pallet_xcm::XcmPassthrough;
 use polkadot_runtime_constants::{
 	currency::CENTS,
@@ -36,13 +40,16 @@ use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, ChildParachainAsNative, ChildParachainConvertsVia,
 	CurrencyAdapter as XcmCurrencyAdapter, DescribeAllTerminal, DescribeFamily, HashedDescription,
-	IsConcrete, MintLocation, OriginToPluralityVoice, SignedAccountId32AsNative,
+	IsConcrete, LocalMint, MintLocation, OriginToPluralityVoice, SignedAccountId32AsNative,
 	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId,
-	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeesToAccount,
+	UsingComponents, WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeesToAccount,
 };
-use xcm_executor::traits::WithOriginFilter;
+use xcm_executor::traits::{MatchesFungibles, WithOriginFilter};
 
-use self::{ethereum::AdapterContractAddress, fungible_adapter::FungibleAdapter};
+use self::{
+	ethereum::{AdapterContractAddress, EvmAssetRate, TreasuryEthAddress},
+	fungible_adapter::FungibleAdapter,
+};
 use super::*;
 
 parameter_types! {
@@ -59,6 +66,13 @@ parameter_types! {
 	pub CheckAccount: AccountId = XcmPallet::check_account();
 	/// The Checking Account along with the indication that the local chain is able to mint tokens.
 	pub LocalCheckAccount: (AccountId, MintLocation) = (CheckAccount::get(), MintLocation::Local);
+	/// H160 mirror of the Checking Account, which holds the EVM assets (BAX and `EvmAssets`)
+	/// that have been teleported out and not back in (yet). Its balance of an asset is the supply
+	/// of the asset on other chains. Amounts teleported out before it was introduced are
+	/// credited to it by [`ReconcileTeleportedSupply`], so they can be teleported back.
+	pub EvmCheckAccount: H160 = *<Runtime as pallet_evm::Config>::CrossAccountId::from_sub(CheckAccount::get()).as_eth();
+	/// The EVM Checking Account along with the indication that the local chain is able to mint EVM assets.
+	pub LocalEvmCheckAccount: (H160, MintLocation) = (EvmCheckAccount::get(), MintLocation::Local);
 	/// Account of the treasury pallet.
 	pub TreasuryAccount: AccountId = Treasury::account_id();
 	pub NativeAssetXcmEvmLocation: Location = Location::new(0, Junction::AccountKey20 { network: Some(ThisNetwork::get()), key: AdapterContractAddress::get().into() });
@@ -128,8 +142,20 @@ pub type EvmAssetsLocationToAccountId20 = (
 	AccountKey20Aliases<ThisNetwork, H160>,
 );
 
-pub type EvmAssetsTransactor =
-	FungiblesAdapter<EvmAssets, EvmAssets, EvmAssetsLocationToAccountId20, H160, NoChecking, ()>;
+pub type EvmAssetsTransactor = FungiblesAdapter<
+	// Use this fungibles implementation:
+	EvmAssets,
+	// Use this fungibles implementation when the asset is one of the `EvmAssets`:
+	EvmAssets,
+	// We can convert the MultiLocations with our converter above:
+	EvmAssetsLocationToAccountId20,
+	// Our EVM account ID type:
+	H160,
+	// We track our teleports in/out of every asset to keep its total issuance correct.
+	LocalMint<Everything>,
+	// The account, which holds the teleported assets:
+	EvmCheckAccount,
+>;
 
 /// Our asset transactor. This is what allows us to interact with the runtime assets from the point
 /// of view of XCM-only concepts like `MultiLocation` and `MultiAsset`.
@@ -157,16 +183,73 @@ pub type EvmLocalAssetTransactor = FungibleAdapter<
 	EvmAssetsLocationToAccountId20,
 	// Our chain's account ID type (we can't get away without mentioning it explicitly):
 	H160,
-	// We don't track any teleports.
-	(),
+	// We track our teleports in/out to keep total issuance correct.
+	LocalEvmCheckAccount,
 >;
 
+/// The EVM assets received from other chains are counted against the flow limits
+/// of `BridgeFlows`. Both EVM transactors are wrapped at once, so every asset is counted once.
 pub type AssetTransactor = (
-	EvmLocalAssetTransactor,
-	EvmAssetsTransactor,
+	LimitInflow<Runtime, (EvmLocalAssetTransactor, EvmAssetsTransactor)>,
 	LocalAssetTransactor,
 );
 
+parameter_types! {
+	/// The amounts of the EVM assets teleported out before their teleports were tracked,
+	/// i.e. their supply on RED, which is the only chain trusted with teleports.
+	///
+	/// The amounts are only known on RED, so they are set by governance through `set_storage`
+	/// under `:TeleportedEvmAssets:` along with the upgrade, and are consumed by
+	/// [`ReconcileTeleportedSupply`].
+	pub storage TeleportedEvmAssets: Vec<(H160, Balance)> = Vec::new();
+}
+
+/// Credits the [`EvmCheckAccount`] with the amounts of the EVM assets, which are held on other
+/// chains, so they can be teleported back. BAX is minted through the `BalancesAdapter`,
+/// other assets through `EvmAssets`.
+///
+/// The total issuance is unchanged from the point of view of all the chains:
+/// the amounts were burned here, when they were teleported out.
+///
+/// The amounts are taken from [`TeleportedEvmAssets`], which is cleared afterwards,
+/// so every amount is credited once.
+pub struct ReconcileTeleportedSupply;
+impl frame_support::traits::OnRuntimeUpgrade for ReconcileTeleportedSupply {
+	fn on_runtime_upgrade() -> Weight {
+		let check_account = EvmCheckAccount::get();
+		let amounts = TeleportedEvmAssets::get();
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+
+		if amounts.is_empty() {
+			log::info!(target: LOG_TARGET, "no teleported EVM assets to reconcile 😏");
+			return db_weight.reads(1);
+		}
+
+		for (asset, amount) in amounts.iter().filter(|(_, amount)| *amount != 0) {
+			let result = if *asset == AdapterContractAddress::get() {
+				BalancesAdapter::mint(&check_account, *amount)
+			} else {
+				match pallet_evm_assets::Pallet::<Runtime>::address_to_asset_id(asset) {
+					Some(id) => EvmAssets::mint(&id, &check_account, *amount),
+					None => Err(pallet_evm_assets::Error::<Runtime>::AssetNotFound.into()),
+				}
+			};
+			if let Err(error) = result {
+				log::error!(
+					target: LOG_TARGET,
+					"failed to reconcile teleported {:?}: {:?}",
+					asset,
+					error
+				);
+			}
+		}
+		frame_support::storage::unhashed::kill(&TeleportedEvmAssets::key());
+
+		log::info!(target: LOG_TARGET, "teleported EVM assets reconciled 🎉");
+		db_weight.reads_writes(1 + amounts.len() as u64 * 2, 1 + amounts.len() as u64 * 3)
+	}
+}
+
 /// The means that we convert an XCM origin `MultiLocation` into the runtime's `Origin` type for
 /// local dispatch. This is a conversion function from an `OriginKind` type along with the
 /// `MultiLocation` value and returns an `Origin` value or an error.
@@ -200,10 +283,19 @@ pub type PriceForChildParachainDelivery = NoPriceForMessageDelivery<<Dmp as FeeT
 
 /// The XCM router. When we want to send an XCM message, we use this type. It amalgamates all of our
 /// individual routers.
-pub type XcmRouter = WithUniqueTopic<(
-	// Only one router so far - use DMP to communicate with child parachains.
-	ChildParachainRouter<Runtime, XcmPallet, PriceForChildParachainDelivery>,
-)>;
+///
+/// Cross-chain transfers from EVM are tracked by the ids assigned by `WithUniqueTopic`.
+pub type XcmRouter = pallet_xcm_transfers::transfer::TrackTransfers<
+	Runtime,
+	WithUniqueTopic<(
+		// Only one router so far - use DMP to communicate with child parachains.
+		ChildParachainRouter<Runtime, XcmPallet, PriceForChildParachainDelivery>,
+	)>,
+>;
+
+/// Outcomes of the cross-chain transfers from EVM are recorded by `XcmTransfers`,
+/// other responses are handled by `XcmPallet`.
+pub type ResponseHandler = pallet_xcm_transfers::transfer::TransferResponses<Runtime, XcmPallet>;
 
 parameter_types! {
 	pub const Dot: MultiAssetFilter = Wild(AllOf { fun: WildFungible, id: Concrete(TokenLocation::get()) });
@@ -250,7 +342,9 @@ impl ContainsPair<MultiAsset, MultiLocation> for EvmAssetsOnRelay {
 		else {
 			return false;
 		};
-		true
+
+		// Chains, which opted into reserve transfers of the asset, are not trusted with teleports
+		ChainRegistry::transfer_mode_at(b, &H160(*key)) == TransferMode::Teleport
 	}
 }
 
@@ -258,11 +352,23 @@ impl ContainsPair<MultiAsset, MultiLocation> for EvmAssetsOnRelay {
 /// ReDeFi Relay recognizes/respects EvmAssets as teleporters.
 pub type TrustedTeleporters = (EvmAssetsOnRelay);
 
-pub struct FreeForAll;
+/// Buys execution with `EvmAssets`, priced in the native currency by `AssetRate`.
+///
+/// Only one asset is accepted per message. The fee is collected on the Treasury's H160 mirror
+/// once the message is executed, after unused weight is refunded.
+pub struct EvmAssetsTrader {
+	weight: Weight,
+	amount: u128,
+	asset: Option<(AssetId, u128)>,
+}
 
-impl WeightTrader for FreeForAll {
+impl WeightTrader for EvmAssetsTrader {
 	fn new() -> Self {
-		Self
+		Self {
+			weight: Weight::zero(),
+			amount: 0,
+			asset: None,
+		}
 	}
 
 	fn buy_weight(
@@ -271,11 +377,73 @@ impl WeightTrader for FreeForAll {
 		payment: Assets,
 		_xcm: &XcmContext,
 	) -> Result<Assets, XcmError> {
-		log::trace!(target: "fassets::weight", "buy_weight weight: {:?}, payment: {:?}", weight, payment);
-		Ok(payment)
+		log::trace!(target: "xcm::weight", "EvmAssetsTrader::buy_weight weight: {:?}, payment: {:?}", weight, payment);
+		let (id, asset_id) = payment
+			.fungible_assets_iter()
+			.find_map(|asset| {
+				let (asset_id, _) = EvmAssets::matches_fungibles(&asset).ok()?;
+				Some((asset.id, asset_id))
+			})
+			.ok_or(XcmError::TooExpensive)?;
+		if matches!(self.asset, Some((bought_with, _)) if bought_with != id) {
+			return Err(XcmError::TooExpensive);
+		}
+
+		let fee = WeightToFee::weight_to_fee(&weight);
+		let amount =
+			EvmAssetRate::to_asset_balance(fee, asset_id).map_err(|()| XcmError::TooExpensive)?;
+		let unused = payment
+			.checked_sub((id, amount).into())
+			.map_err(|_| XcmError::TooExpensive)?;
+
+		self.weight = self.weight.saturating_add(weight);
+		self.amount = self.amount.saturating_add(amount);
+		self.asset = Some((id, asset_id));
+		Ok(unused)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, _xcm: &XcmContext) -> Option<MultiAsset> {
+		log::trace!(target: "xcm::weight", "EvmAssetsTrader::refund_weight weight: {:?}", weight);
+		let (id, asset_id) = self.asset?;
+		let weight = weight.min(self.weight);
+		let fee = WeightToFee::weight_to_fee(&weight);
+		let amount = EvmAssetRate::to_asset_balance(fee, asset_id)
+			.ok()?
+			.min(self.amount);
+
+		self.weight = self.weight.saturating_sub(weight);
+		self.amount = self.amount.saturating_sub(amount);
+		(amount > 0).then(|| (id, amount).into())
+	}
+}
+
+impl Drop for EvmAssetsTrader {
+	fn drop(&mut self) {
+		let Some((_, asset_id)) = self.asset else {
+			return;
+		};
+		if self.amount == 0 {
+			return;
+		}
+
+		if let Err(error) = <EvmAssets as fungibles::Mutate<H160>>::mint_into(
+			asset_id,
+			&TreasuryEthAddress::get(),
+			self.amount,
+		) {
+			log::error!(target: "xcm::weight", "Failed to collect the fee of {} in asset {}: {:?}", self.amount, asset_id, error);
+		}
 	}
 }
 
+/// Execution is paid in BAX, either by its native location or by the location of its EVM adapter,
+/// or in `EvmAssets`. Revenue goes to the Treasury.
+pub type Trader = (
+	UsingComponents<WeightToFee, TokenLocation, AccountId, Balances, Treasury>,
+	UsingComponents<WeightToFee, NativeAssetXcmEvmLocation, AccountId, Balances, Treasury>,
+	EvmAssetsTrader,
+);
+
 match_types! {
 	pub type OnlyParachains: impl Contains<MultiLocation> = {
 		MultiLocation { parents: 0, interior: X1(Parachain(_)) }
@@ -291,7 +459,7 @@ pub type Barrier = TrailingSetTopicAsId<(
 	// Weight that is paid for may be consumed.
 	TakeWeightCredit,
 	// Expected responses are OK.
-	AllowKnownQueryResponses<XcmPallet>,
+	AllowKnownQueryResponses<ResponseHandler>,
 	WithComputedOrigin<
 		(
 			// If the message is one that immediately attempts to pay for execution, then allow it.
@@ -316,7 +484,8 @@ impl xcm_executor::Config for XcmConfig {
 	type XcmSender = XcmRouter;
 	type AssetTransactor = AssetTransactor;
 	type OriginConverter = LocalOriginConverter;
-	// Polkadot Relay recognises no chains which act as reserves.
+	// This chain is the reserve of its assets. The assets reserve-transferred to a chain come back
+	// by a withdrawal from the sovereign account of the chain, so no other chain acts as a reserve.
 	type IsReserve = ();
 	type IsTeleporter = TrustedTeleporters;
 	type UniversalLocation = UniversalLocation;
@@ -327,8 +496,8 @@ impl xcm_executor::Config for XcmConfig {
 		MaxInstructions,
 	>;
 	// The weight trader piggybacks on the existing transaction-fee conversion logic.
-	type Trader = FreeForAll;
-	type ResponseHandler = XcmPallet;
+	type Trader = Trader;
+	type ResponseHandler = ResponseHandler;
 	type AssetTrap = XcmPallet;
 	type AssetLocker = ();
 	type AssetExchanger = ();
@@ -434,3 +603,254 @@ impl pallet_xcm::Config for Runtime {
 	type ReachableDest = ReachableDest;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
+
+#[cfg(test)]
+mod tests {
+	use frame_support::traits::OnRuntimeUpgrade;
+	use sp_runtime::BuildStorage;
+	use xcm_executor::XcmExecutor;
+
+	use super::*;
+
+	const RED: H160 = H160(hex_literal::hex!(
+		"FFFFFFFFBABB0000000000000000000000000000"
+	));
+	const GBP: H160 = H160(hex_literal::hex!(
+		"FFFFFFFFBABB0000000000000000000000000010"
+	));
+	const HOLDER: H160 = H160([0x22; 20]);
+	const RECEIVER: H160 = H160([0x33; 20]);
+	const TELEPORTED: Balance = 1_000;
+	const RESERVED: Balance = 1_000;
+	const PARA_ID: u32 = 2_000;
+
+	fn red_id() -> u128 {
+		EvmAssets::address_to_asset_id(&RED).unwrap()
+	}
+
+	fn reconcile_red() {
+		TeleportedEvmAssets::set(&vec![(RED, TELEPORTED)]);
+		ReconcileTeleportedSupply::on_runtime_upgrade();
+	}
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut storage = frame_system::GenesisConfig::<Runtime>::default()
+			.build_storage()
+			.unwrap();
+		pallet_evm_assets::GenesisConfig::<Runtime> {
+			accounts: vec![
+				<Runtime as pallet_evm::Config>::CrossAccountId::from_eth(HOLDER)
+					.as_sub()
+					.clone(),
+			],
+			owner: None,
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+		storage.into()
+	}
+
+	fn red(amount: Balance) -> MultiAsset {
+		(
+			MultiLocation::new(
+				0,
+				X1(AccountKey20 {
+					network: Some(ThisNetwork::get()),
+					key: RED.into(),
+				}),
+			),
+			amount,
+		)
+			.into()
+	}
+
+	fn deposit_to_receiver() -> Instruction<RuntimeCall> {
+		DepositAsset {
+			assets: Wild(AllCounted(1)),
+			beneficiary: AccountKey20 {
+				network: None,
+				key: RECEIVER.into(),
+			}
+			.into(),
+		}
+	}
+
+	fn execute(
+		origin: impl Into<MultiLocation>,
+		message: Vec<Instruction<RuntimeCall>>,
+	) -> Outcome {
+		XcmExecutor::<XcmConfig>::execute_xcm_in_credit(
+			origin,
+			Xcm(message),
+			[0; 32],
+			Weight::MAX,
+			Weight::MAX,
+		)
+	}
+
+	fn teleport_back(amount: Balance) -> Outcome {
+		execute(
+			Parachain(RED_ID),
+			vec![
+				ReceiveTeleportedAsset(red(amount).into()),
+				deposit_to_receiver(),
+			],
+		)
+	}
+
+	#[test]
+	fn reconciled_supply_is_teleported_back() {
+		new_test_ext().execute_with(|| {
+			let supply = EvmAssets::total_supply(&red_id());
+
+			reconcile_red();
+			assert_eq!(
+				EvmAssets::balance(&red_id(), &EvmCheckAccount::get()),
+				TELEPORTED
+			);
+			assert_eq!(EvmAssets::total_supply(&red_id()), supply + TELEPORTED);
+
+			// Reconciled once
+			ReconcileTeleportedSupply::on_runtime_upgrade();
+			assert_eq!(
+				EvmAssets::balance(&red_id(), &EvmCheckAccount::get()),
+				TELEPORTED
+			);
+
+			assert!(matches!(
+				teleport_back(TELEPORTED / 2),
+				Outcome::Complete(_)
+			));
+			assert_eq!(EvmAssets::balance(&red_id(), &RECEIVER), TELEPORTED / 2);
+			assert_eq!(
+				EvmAssets::balance(&red_id(), &EvmCheckAccount::get()),
+				TELEPORTED / 2
+			);
+			assert_eq!(EvmAssets::total_supply(&red_id()), supply + TELEPORTED);
+		});
+	}
+
+	#[test]
+	fn supply_of_runtime_assets_is_reconciled_from_storage() {
+		new_test_ext().execute_with(|| {
+			let gbp_id = EvmAssets::address_to_asset_id(&GBP).unwrap();
+			let check_account =
+				<Runtime as pallet_evm::Config>::CrossAccountId::from_eth(EvmCheckAccount::get());
+			let red_supply = EvmAssets::total_supply(&red_id());
+			let gbp_supply = EvmAssets::total_supply(&gbp_id);
+
+			// Nothing is reconciled until governance sets the amounts
+			ReconcileTeleportedSupply::on_runtime_upgrade();
+			assert_eq!(BalancesAdapter::balance_of(&check_account), 0);
+
+			frame_support::storage::unhashed::put(
+				&TeleportedEvmAssets::key(),
+				&vec![
+					(AdapterContractAddress::get(), 3 * TELEPORTED),
+					(RED, TELEPORTED),
+					(GBP, 2 * TELEPORTED),
+				],
+			);
+			ReconcileTeleportedSupply::on_runtime_upgrade();
+			assert_eq!(BalancesAdapter::balance_of(&check_account), 3 * TELEPORTED);
+			assert_eq!(
+				EvmAssets::balance(&red_id(), &EvmCheckAccount::get()),
+				TELEPORTED
+			);
+			assert_eq!(EvmAssets::total_supply(&red_id()), red_supply + TELEPORTED);
+			assert_eq!(
+				EvmAssets::balance(&gbp_id, &EvmCheckAccount::get()),
+				2 * TELEPORTED
+			);
+			assert_eq!(
+				EvmAssets::total_supply(&gbp_id),
+				gbp_supply + 2 * TELEPORTED
+			);
+
+			// The amounts are consumed
+			assert!(TeleportedEvmAssets::get().is_empty());
+			ReconcileTeleportedSupply::on_runtime_upgrade();
+			assert_eq!(BalancesAdapter::balance_of(&check_account), 3 * TELEPORTED);
+		});
+	}
+
+	#[test]
+	fn teleport_back_is_limited_by_check_account() {
+		new_test_ext().execute_with(|| {
+			let supply = EvmAssets::total_supply(&red_id());
+			reconcile_red();
+
+			assert!(!matches!(
+				teleport_back(TELEPORTED + 1),
+				Outcome::Complete(_)
+			));
+			assert_eq!(EvmAssets::balance(&red_id(), &RECEIVER), 0);
+			assert_eq!(EvmAssets::total_supply(&red_id()), supply + TELEPORTED);
+		});
+	}
+
+	#[test]
+	fn reserve_transferred_assets_are_withdrawn_back() {
+		new_test_ext().execute_with(|| {
+			let sovereign =
+				EvmAssetsLocationToAccountId20::convert_location(&Parachain(PARA_ID).into())
+					.unwrap();
+			EvmAssets::mint(&red_id(), &HOLDER, RESERVED).unwrap();
+			let supply = EvmAssets::total_supply(&red_id());
+
+			// The local part of a reserve transfer keeps the asset on the sovereign account
+			assert!(matches!(
+				execute(
+					AccountKey20 {
+						network: None,
+						key: HOLDER.into(),
+					},
+					vec![TransferAsset {
+						assets: red(RESERVED).into(),
+						beneficiary: Parachain(PARA_ID).into(),
+					}],
+				),
+				Outcome::Complete(_)
+			));
+			assert_eq!(EvmAssets::balance(&red_id(), &sovereign), RESERVED);
+
+			// The chain is not the reserve of the asset and can't mint it here
+			assert!(!matches!(
+				execute(
+					Parachain(PARA_ID),
+					vec![
+						ReserveAssetDeposited(red(RESERVED).into()),
+						deposit_to_receiver()
+					],
+				),
+				Outcome::Complete(_)
+			));
+			assert_eq!(EvmAssets::balance(&red_id(), &RECEIVER), 0);
+
+			// The asset comes back from the sovereign account
+			assert!(matches!(
+				execute(
+					Parachain(PARA_ID),
+					vec![
+						WithdrawAsset(red(RESERVED / 2).into()),
+						deposit_to_receiver()
+					],
+				),
+				Outcome::Complete(_)
+			));
+			assert_eq!(EvmAssets::balance(&red_id(), &RECEIVER), RESERVED / 2);
+			assert_eq!(EvmAssets::balance(&red_id(), &sovereign), RESERVED / 2);
+
+			// ...but no more than it keeps
+			assert!(!matches!(
+				execute(
+					Parachain(PARA_ID),
+					vec![WithdrawAsset(red(RESERVED).into()), deposit_to_receiver()],
+				),
+				Outcome::Complete(_)
+			));
+			assert_eq!(EvmAssets::balance(&red_id(), &RECEIVER), RESERVED / 2);
+			assert_eq!(EvmAssets::total_supply(&red_id()), supply);
+		});
+	}
+}